@@ -1,12 +1,16 @@
-use std::{f32::consts::TAU, sync::Arc};
+use std::{f32::consts::TAU, io::Cursor, sync::Arc};
 
-use criterion::{criterion_group, criterion_main, Criterion};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use kira::{
 	manager::{
 		backend::mock::{MockBackend, MockBackendSettings},
 		AudioManager, AudioManagerSettings, Capacities,
 	},
-	sound::static_sound::{StaticSoundData, StaticSoundSettings},
+	sound::{
+		compressed::CompressedSoundData,
+		static_sound::{StaticSoundData, StaticSoundSettings},
+		PlaybackState,
+	},
 	Frame,
 };
 
@@ -26,6 +30,9 @@ fn create_test_sound(num_samples: usize) -> StaticSoundData {
 	}
 }
 
+// a few seconds of synthesized music encoded as OGG Vorbis
+const TEST_OGG: &[u8] = include_bytes!("../assets/music.ogg");
+
 fn sounds(c: &mut Criterion) {
 	// a simple test case where many sounds are being played at once
 	c.bench_function("simple", |b| {
@@ -85,5 +92,83 @@ fn sounds(c: &mut Criterion) {
 	});
 }
 
-criterion_group!(benches, sounds);
+// compares playing static sounds with playing compressed sounds
+// made from the same OGG file. compressed sounds are decoded on a
+// background thread while they play, and they can't finish until
+// all of their audio is decoded, so playing them to the end includes
+// the cost of decoding each instance.
+fn compressed_sounds(c: &mut Criterion) {
+	const SAMPLE_RATE: u32 = 48_000;
+	const NUM_SOUNDS: u16 = 10;
+
+	let static_sound_data = StaticSoundData::from_cursor(Cursor::new(TEST_OGG)).unwrap();
+	let compressed_sound_data = CompressedSoundData::from_cursor(Cursor::new(TEST_OGG)).unwrap();
+
+	// finished sounds aren't removed until the next iteration
+	// starts processing, so leave room for two sets of sounds
+	let create_manager = || {
+		AudioManager::<MockBackend>::new(AudioManagerSettings {
+			capacities: Capacities {
+				command_capacity: NUM_SOUNDS as usize * 2,
+				sound_capacity: NUM_SOUNDS * 2,
+				..Default::default()
+			},
+			backend_settings: MockBackendSettings {
+				sample_rate: SAMPLE_RATE,
+			},
+			..Default::default()
+		})
+		.unwrap()
+	};
+
+	let mut group = c.benchmark_group("playing sounds to the end");
+	group.sample_size(10);
+
+	group.bench_function("static sounds", |b| {
+		let mut manager = create_manager();
+		b.iter(|| {
+			let handles = (0..NUM_SOUNDS)
+				.map(|_| manager.play(static_sound_data.clone()).unwrap())
+				.collect::<Vec<_>>();
+			process_until(&mut manager, || {
+				handles
+					.iter()
+					.all(|handle| handle.state() == PlaybackState::Stopped)
+			});
+		});
+	});
+
+	group.bench_function("compressed sounds", |b| {
+		let mut manager = create_manager();
+		b.iter(|| {
+			let handles = (0..NUM_SOUNDS)
+				.map(|_| manager.play(compressed_sound_data.clone()).unwrap())
+				.collect::<Vec<_>>();
+			process_until(&mut manager, || {
+				handles
+					.iter()
+					.all(|handle| handle.state() == PlaybackState::Stopped)
+			});
+		});
+	});
+
+	group.finish();
+}
+
+// processes buffers of audio, like an audio callback would,
+// until `finished` returns `true`
+fn process_until(manager: &mut AudioManager<MockBackend>, mut finished: impl FnMut() -> bool) {
+	const BUFFER_SIZE: usize = 256;
+	while !finished() {
+		manager.backend_mut().on_start_processing();
+		for _ in 0..BUFFER_SIZE {
+			black_box(manager.backend_mut().process());
+		}
+		// like a real audio callback, give other threads
+		// a chance to run between buffers
+		std::thread::yield_now();
+	}
+}
+
+criterion_group!(benches, sounds, compressed_sounds);
 criterion_main!(benches);
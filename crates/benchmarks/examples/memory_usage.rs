@@ -0,0 +1,127 @@
+//! Reports how much memory static and compressed sounds use.
+//!
+//! Run with `cargo run --release -p benchmarks --example memory_usage`.
+
+use std::{
+	alloc::{GlobalAlloc, Layout, System},
+	io::Cursor,
+	sync::atomic::{AtomicUsize, Ordering},
+	time::Duration,
+};
+
+use kira::{
+	manager::{
+		backend::mock::{MockBackend, MockBackendSettings},
+		AudioManager, AudioManagerSettings, Capacities,
+	},
+	sound::{compressed::CompressedSoundData, static_sound::StaticSoundData, SoundData},
+};
+
+// a few seconds of synthesized music encoded as OGG Vorbis
+const TEST_OGG: &[u8] = include_bytes!("../assets/music.ogg");
+const SAMPLE_RATE: u32 = 48_000;
+const NUM_SOUNDS: u16 = 10;
+
+static NUM_ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+// keeps track of how many bytes are allocated at any given time
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let ptr = System.alloc(layout);
+		if !ptr.is_null() {
+			NUM_ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::SeqCst);
+		}
+		ptr
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout);
+		NUM_ALLOCATED_BYTES.fetch_sub(layout.size(), Ordering::SeqCst);
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn main() {
+	let (static_sound_data, static_data_bytes) =
+		measure(|| StaticSoundData::from_cursor(Cursor::new(TEST_OGG)).unwrap());
+	let (compressed_sound_data, compressed_data_bytes) =
+		measure(|| CompressedSoundData::from_cursor(Cursor::new(TEST_OGG)).unwrap());
+	println!(
+		"memory used by {:.1} seconds of audio:",
+		static_sound_data.duration().as_secs_f64()
+	);
+	println!("  static sound data: {} bytes", static_data_bytes);
+	println!("  compressed sound data: {} bytes", compressed_data_bytes);
+
+	println!("memory used by each playing instance:");
+	println!(
+		"  static sound: {} bytes",
+		instance_bytes(static_sound_data)
+	);
+	// the rest of a compressed sound's memory is used by its decoder,
+	// so changing the buffer size shows how much the buffer takes up
+	for buffer_size in [512, 2048, 8192] {
+		println!(
+			"  compressed sound with a {} frame buffer: {} bytes",
+			buffer_size,
+			instance_bytes(compressed_sound_data.buffer_size(buffer_size))
+		);
+	}
+}
+
+// returns the result of `f` and how many more bytes are
+// allocated after calling it than before
+fn measure<T>(f: impl FnOnce() -> T) -> (T, usize) {
+	let num_bytes_before = NUM_ALLOCATED_BYTES.load(Ordering::SeqCst);
+	let value = f();
+	let num_bytes_after = NUM_ALLOCATED_BYTES.load(Ordering::SeqCst);
+	(value, num_bytes_after.saturating_sub(num_bytes_before))
+}
+
+// plays several instances of a sound and returns the average number
+// of bytes each instance uses while it's playing
+fn instance_bytes<D: SoundData + Clone>(sound_data: D) -> usize
+where
+	D::Error: std::fmt::Debug,
+{
+	const BUFFER_SIZE: usize = 256;
+	const NUM_BUFFERS: usize = 10;
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+		capacities: Capacities {
+			command_capacity: NUM_SOUNDS as usize,
+			sound_capacity: NUM_SOUNDS,
+			..Default::default()
+		},
+		backend_settings: MockBackendSettings {
+			sample_rate: SAMPLE_RATE,
+		},
+		..Default::default()
+	})
+	.unwrap();
+	let (handles, num_bytes) = measure(|| {
+		let handles = (0..NUM_SOUNDS)
+			.map(|_| manager.play(sound_data.clone()).unwrap())
+			.collect::<Vec<_>>();
+		// play the sounds for a bit so any audio that's decoded
+		// during playback is included
+		for _ in 0..NUM_BUFFERS {
+			manager.backend_mut().on_start_processing();
+			for _ in 0..BUFFER_SIZE {
+				let _ = manager.backend_mut().process();
+			}
+			std::thread::sleep(Duration::from_millis(10));
+		}
+		handles
+	});
+	// free the sounds before the next measurement. the decode thread
+	// drops compressed sounds the next time it checks on them, so
+	// give it some time to do that
+	drop(handles);
+	drop(manager);
+	std::thread::sleep(Duration::from_millis(100));
+	num_bytes / NUM_SOUNDS as usize
+}
@@ -267,3 +267,8 @@ pub use frame::*;
 pub use output_destination::*;
 pub use start_time::*;
 pub use volume::*;
+
+// lets tests check that code running on the audio thread doesn't allocate
+#[cfg(all(test, feature = "assert_no_alloc"))]
+#[global_allocator]
+static ALLOCATOR: assert_no_alloc::AllocDisabler = assert_no_alloc::AllocDisabler;
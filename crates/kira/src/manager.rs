@@ -112,9 +112,10 @@ impl<B: Backend> AudioManager<B> {
 		&mut self,
 		sound_data: D,
 	) -> Result<D::Handle, PlaySoundError<D::Error>> {
-		let (sound, handle) = sound_data
+		let (mut sound, handle) = sound_data
 			.into_sound()
 			.map_err(PlaySoundError::IntoSoundError)?;
+		sound.init(self.renderer_shared.sample_rate.load(Ordering::SeqCst));
 		self.resource_controllers
 			.sound_controller
 			.insert(sound)
@@ -54,6 +54,7 @@ impl Renderer {
 	pub fn on_change_sample_rate(&mut self, sample_rate: u32) {
 		self.dt = 1.0 / sample_rate as f64;
 		self.shared.sample_rate.store(sample_rate, Ordering::SeqCst);
+		self.resources.sounds.on_change_sample_rate(sample_rate);
 		self.resources.mixer.on_change_sample_rate(sample_rate);
 	}
 
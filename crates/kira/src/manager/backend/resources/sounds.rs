@@ -14,6 +14,12 @@ impl Sounds {
 		(Self(storage), controller)
 	}
 
+	pub fn on_change_sample_rate(&mut self, sample_rate: u32) {
+		for (_, sound) in &mut self.0 {
+			sound.on_change_sample_rate(sample_rate);
+		}
+	}
+
 	pub fn on_start_processing(&mut self) {
 		self.0.remove_and_add(|sound| sound.finished());
 		for (_, sound) in &mut self.0 {
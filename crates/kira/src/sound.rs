@@ -2,7 +2,7 @@
 Sources of audio.
 
 Any type that implements [`SoundData`] can be played using
-[`AudioManager::play`](crate::manager::AudioManager::play). Kira comes with two
+[`AudioManager::play`](crate::manager::AudioManager::play). Kira comes with three
 [`SoundData`] implementations:
 
 - [`StaticSoundData`](static_sound::StaticSoundData), which loads an entire chunk of audio
@@ -11,15 +11,22 @@ or sounds where consistent start times are important.
 - [`StreamingSoundData`](streaming::StreamingSoundData), which streams audio from a file or cursor
 (only available on desktop platforms). This is more appropriate for long sounds that you only
 play once at a time, like background music. Streaming sounds use less memory than static sounds.
+- [`CompressedSoundData`](compressed::CompressedSoundData), which keeps an encoded audio file
+in memory and decodes it as it plays (only available on desktop platforms with the `symphonia` feature). This is a
+middle ground for longer sounds that would use too much memory as static sounds, but that can't
+be streamed from disk or need to be played several times at once.
 
-These two sound types should cover most use cases, but if you need something else, you can
+These sound types should cover most use cases, but if you need something else, you can
 create your own types that implement the [`SoundData`] and [`Sound`] traits.
 */
 
+#[cfg(all(feature = "symphonia", not(target_arch = "wasm32")))]
+pub mod compressed;
 #[cfg(feature = "symphonia")]
 mod error;
 mod playback_position;
 mod playback_rate;
+mod processor;
 pub mod static_sound;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod streaming;
@@ -34,6 +41,7 @@ use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
 pub use error::*;
 pub use playback_position::*;
 pub use playback_rate::*;
+pub use processor::*;
 
 use crate::{
 	clock::clock_info::ClockInfoProvider, frame::Frame,
@@ -72,6 +80,12 @@ pub trait Sound: Send {
 	#[must_use]
 	fn output_destination(&mut self) -> OutputDestination;
 
+	/// Called once when the sound is played, before it is sent to the renderer.
+	fn init(&mut self, sample_rate: u32) {}
+
+	/// Called when the sample rate of the renderer is changed.
+	fn on_change_sample_rate(&mut self, sample_rate: u32) {}
+
 	/// Called whenever a new batch of audio samples is requested by the backend.
 	///
 	/// This is a good place to put code that needs to run fairly frequently,
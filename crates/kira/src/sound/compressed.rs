@@ -0,0 +1,57 @@
+/*!
+Playable chunks of audio that stay encoded in memory and are decoded during playback.
+
+To play a compressed sound, pass a [`CompressedSoundData`] to
+[`AudioManager::play`](crate::manager::AudioManager::play).
+
+```no_run
+use kira::{
+	manager::{
+		AudioManager, AudioManagerSettings,
+		backend::DefaultBackend,
+	},
+	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+};
+
+let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+let sound_data = CompressedSoundData::from_file("sound.ogg")?;
+manager.play(sound_data)?;
+# Result::<(), Box<dyn std::error::Error>>::Ok(())
+```
+
+Compressed sounds sit between static and streaming sounds. Like static sounds,
+the audio is kept in memory, so cloning a [`CompressedSoundData`] is cheap and
+the same sound can be played many times at once. Unlike static sounds, the audio
+is kept in its original encoded form, so an OGG or MP3 file takes up roughly as
+much memory as it does on disk. The tradeoff is CPU usage: like a streaming sound,
+each playing instance decodes its own audio, so compressed sounds can have delays
+when starting or seeking, and they are best suited for longer sounds that would
+take up too much memory as static sounds.
+
+The audio for every playing compressed sound is decoded on a single background
+thread. Each instance has its own decoder, which for an OGG file takes up a few
+hundred kilobytes, and a small buffer of decoded audio, which can be adjusted
+with [`CompressedSoundSettings::buffer_size`].
+[`CompressedSoundData::max_instances`] can be used to put an upper bound on how
+many instances of a sound can be decoding at once.
+
+Unlike static sounds, compressed sounds cannot be played backwards. If the
+playback rate of a compressed sound becomes negative, the sound will pause
+in place until the playback rate is positive again.
+*/
+
+#![cfg_attr(docsrs, doc(cfg(not(wasm32))))]
+
+mod data;
+mod decode_thread;
+mod error;
+mod handle;
+mod settings;
+mod sound;
+#[cfg(all(test, feature = "wav"))]
+mod wav;
+
+pub use data::*;
+pub use error::*;
+pub use handle::*;
+pub use settings::*;
@@ -0,0 +1,536 @@
+#[cfg(all(test, feature = "wav"))]
+mod test;
+
+use std::{
+	fmt::{Debug, Formatter},
+	io::Cursor,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
+
+use crate::{
+	sound::{
+		streaming::{
+			decoder::symphonia::SymphoniaDecoder, sound::decode_scheduler::DecodeScheduler,
+			Decoder, StreamingSoundData,
+		},
+		EndPosition, FromFileError, IntoOptionalRegion, PlaybackPosition, PlaybackRate, Region,
+		Sound, SoundData,
+	},
+	tween::{Tween, Value},
+	OutputDestination, StartTime, Volume,
+};
+
+use super::{
+	decode_thread, handle::CompressedSoundHandle, sound::CompressedSound, CompressedSoundSettings,
+	PlayCompressedSoundError,
+};
+
+/// An encoded audio file held in memory that is decoded as it plays.
+///
+/// These can be cheaply cloned, as the encoded audio and the count
+/// of playing instances are shared among all clones.
+#[derive(Clone)]
+pub struct CompressedSoundData {
+	bytes: Arc<[u8]>,
+	sample_rate: u32,
+	total_num_frames: usize,
+	num_instances: Arc<AtomicUsize>,
+	/// Settings for the sound.
+	pub settings: CompressedSoundSettings,
+	/**
+	The portion of the sound this [`CompressedSoundData`] represents.
+
+	Note that the [`CompressedSoundData`] holds the entire piece of audio
+	it was originally given regardless of the value of `slice`, but
+	[`CompressedSoundData::num_frames`] and [`CompressedSoundData::duration`]
+	will behave as if this [`CompressedSoundData`] only contained the specified
+	portion of audio.
+	*/
+	pub slice: Option<(usize, usize)>,
+	/**
+	The maximum number of instances of this sound that can be playing
+	at the same time, or `None` if there is no limit.
+
+	Each playing instance decodes its own audio, so this can be used to
+	put an upper bound on the CPU usage of a sound that is played often.
+	The limit is checked against instances played from this
+	[`CompressedSoundData`] and any of its clones. An instance stops
+	counting towards the limit as soon as it stops playing.
+	*/
+	pub max_instances: Option<usize>,
+}
+
+impl CompressedSoundData {
+	/// Loads an audio file into a [`CompressedSoundData`].
+	///
+	/// The file is read into memory, but it is not decoded.
+	pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, FromFileError> {
+		Self::from_bytes(std::fs::read(path)?.into())
+	}
+
+	/// Loads a cursor wrapping audio file data into a [`CompressedSoundData`].
+	///
+	/// The audio data is copied into memory shared by all clones of the
+	/// [`CompressedSoundData`], but it is not decoded.
+	pub fn from_cursor<T: AsRef<[u8]>>(cursor: Cursor<T>) -> Result<Self, FromFileError> {
+		Self::from_bytes(cursor.into_inner().as_ref().into())
+	}
+
+	fn from_bytes(bytes: Arc<[u8]>) -> Result<Self, FromFileError> {
+		// create a decoder up front to make sure the audio can be decoded
+		// and to read the sample rate and duration
+		let decoder = SymphoniaDecoder::new(Box::new(Cursor::new(bytes.clone())))?;
+		Ok(Self {
+			bytes,
+			sample_rate: decoder.sample_rate(),
+			total_num_frames: decoder.num_frames(),
+			num_instances: Arc::new(AtomicUsize::new(0)),
+			settings: CompressedSoundSettings::default(),
+			slice: None,
+			max_instances: None,
+		})
+	}
+
+	/**
+	Sets when the sound should start playing.
+
+	This returns a cheap clone of the [`CompressedSoundData`] with the modified start time.
+
+	# Examples
+
+	Configuring a sound to start 4 ticks after a clock's current time:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+		clock::ClockSpeed,
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let clock_handle = manager.add_clock(ClockSpeed::TicksPerMinute(120.0))?;
+	let sound = CompressedSoundData::from_file("sound.ogg")?
+		.start_time(clock_handle.time() + 4);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn start_time(&self, start_time: impl Into<StartTime>) -> Self {
+		let mut new = self.clone();
+		new.settings.start_time = start_time.into();
+		new
+	}
+
+	/// Sets where in the sound playback should start.
+	///
+	/// This returns a cheap clone of the [`CompressedSoundData`] with the modified start position.
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn start_position(&self, start_position: impl Into<PlaybackPosition>) -> Self {
+		let mut new = self.clone();
+		new.settings.start_position = start_position.into();
+		new
+	}
+
+	/**
+	Sets the portion of the sound that should be looped.
+
+	This returns a cheap clone of the [`CompressedSoundData`] with the modified loop region.
+
+	# Examples
+
+	Configure a sound to loop the portion from 3 seconds in to the end:
+
+	```
+	# use kira::sound::compressed::CompressedSoundSettings;
+	let settings = CompressedSoundSettings::new().loop_region(3.0..);
+	```
+
+	Configure a sound to loop the portion from 2 to 4 seconds:
+
+	```
+	# use kira::sound::compressed::CompressedSoundSettings;
+	let settings = CompressedSoundSettings::new().loop_region(2.0..4.0);
+	```
+	*/
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn loop_region(&self, loop_region: impl IntoOptionalRegion) -> Self {
+		let mut new = self.clone();
+		new.settings.loop_region = loop_region.into_optional_region();
+		new
+	}
+
+	/**
+	Sets the volume of the sound.
+
+	This returns a cheap clone of the [`CompressedSoundData`] with the modified volume.
+
+	# Examples
+
+	Set the volume as a factor:
+
+	```
+	# use kira::sound::compressed::CompressedSoundSettings;
+	let settings = CompressedSoundSettings::new().volume(0.5);
+	```
+
+	Set the volume as a gain in decibels:
+
+	```
+	# use kira::sound::compressed::CompressedSoundSettings;
+	let settings = CompressedSoundSettings::new().volume(kira::Volume::Decibels(-6.0));
+	```
+
+	Link the volume to a modulator:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		modulator::tweener::TweenerBuilder,
+		sound::compressed::{CompressedSoundSettings},
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let tweener = manager.add_modulator(TweenerBuilder {
+		initial_value: 0.5,
+	})?;
+	let settings = CompressedSoundSettings::new().volume(&tweener);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn volume(&self, volume: impl Into<Value<Volume>>) -> Self {
+		let mut new = self.clone();
+		new.settings.volume = volume.into();
+		new
+	}
+
+	/**
+	Sets the playback rate of the sound.
+
+	Changing the playback rate will change both the speed and the pitch of the sound.
+	Compressed sounds cannot be played backwards, so negative playback rates
+	are treated as 0.
+
+	This returns a cheap clone of the [`CompressedSoundData`] with the modified playback rate.
+
+	# Examples
+
+	Set the playback rate as a factor:
+
+	```
+	# use kira::sound::compressed::CompressedSoundSettings;
+	let settings = CompressedSoundSettings::new().playback_rate(0.5);
+	```
+
+	Set the playback rate as a change in semitones:
+
+	```
+	# use kira::sound::compressed::CompressedSoundSettings;
+	use kira::sound::PlaybackRate;
+	let settings = CompressedSoundSettings::new().playback_rate(PlaybackRate::Semitones(-2.0));
+	```
+
+	Link the playback rate to a modulator:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		modulator::tweener::TweenerBuilder,
+		sound::compressed::{CompressedSoundSettings},
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let tweener = manager.add_modulator(TweenerBuilder {
+		initial_value: 0.5,
+	})?;
+	let settings = CompressedSoundSettings::new().playback_rate(&tweener);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn playback_rate(&self, playback_rate: impl Into<Value<PlaybackRate>>) -> Self {
+		let mut new = self.clone();
+		new.settings.playback_rate = playback_rate.into();
+		new
+	}
+
+	/**
+	Sets the panning of the sound, where 0 is hard left and 1 is hard right.
+
+	This returns a cheap clone of the [`CompressedSoundData`] with the modified panning.
+
+	# Examples
+
+	Set the panning to a static value:
+
+	```
+	# use kira::sound::compressed::CompressedSoundSettings;
+	let settings = CompressedSoundSettings::new().panning(0.25);
+	```
+
+	Link the panning to a modulator:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		modulator::tweener::TweenerBuilder,
+		sound::compressed::{CompressedSoundSettings},
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let tweener = manager.add_modulator(TweenerBuilder {
+		initial_value: 0.25,
+	})?;
+	let settings = CompressedSoundSettings::new().panning(&tweener);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn panning(&self, panning: impl Into<Value<f64>>) -> Self {
+		let mut new = self.clone();
+		new.settings.panning = panning.into();
+		new
+	}
+
+	/**
+	Sets the destination that this sound should be routed to.
+
+	This returns a cheap clone of the [`CompressedSoundData`] with the modified output destination.
+
+	# Examples
+
+	Set the output destination of a sound to a mixer track:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		track::TrackBuilder,
+		sound::compressed::{CompressedSoundSettings},
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let sub_track = manager.add_sub_track(TrackBuilder::new())?;
+	let settings = CompressedSoundSettings::new().output_destination(&sub_track);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Set the output destination of a sound to an emitter in a spatial scene:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		spatial::{scene::SpatialSceneSettings, emitter::EmitterSettings},
+		sound::compressed::{CompressedSoundSettings},
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let mut scene = manager.add_spatial_scene(SpatialSceneSettings::default())?;
+	let emitter = scene.add_emitter(mint::Vector3 {
+		x: 0.0,
+		y: 0.0,
+		z: 0.0,
+	}, EmitterSettings::default())?;
+	let settings = CompressedSoundSettings::new().output_destination(&emitter);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn output_destination(&self, output_destination: impl Into<OutputDestination>) -> Self {
+		let mut new = self.clone();
+		new.settings.output_destination = output_destination.into();
+		new
+	}
+
+	/// Sets the tween used to fade in the sound from silence.
+	///
+	/// This returns a cheap clone of the [`CompressedSoundData`] with the modified fade in tween.
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn fade_in_tween(&self, fade_in_tween: impl Into<Option<Tween>>) -> Self {
+		let mut new = self.clone();
+		new.settings.fade_in_tween = fade_in_tween.into();
+		new
+	}
+
+	/// Sets how many frames of audio each instance of the sound can
+	/// decode ahead of the playback position.
+	///
+	/// This returns a cheap clone of the [`CompressedSoundData`] with the modified buffer size.
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn buffer_size(&self, buffer_size: usize) -> Self {
+		let mut new = self.clone();
+		new.settings.buffer_size = buffer_size;
+		new
+	}
+
+	/// Returns a cheap clone of the `CompressedSoundData` with the specified settings.
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn with_settings(&self, settings: CompressedSoundSettings) -> Self {
+		Self {
+			settings,
+			..self.clone()
+		}
+	}
+
+	/// Sets the maximum number of instances of this sound that can be
+	/// playing at the same time.
+	///
+	/// This returns a cheap clone of the [`CompressedSoundData`] with the modified limit.
+	/// The clone still shares its count of playing instances with the original.
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn max_instances(&self, max_instances: impl Into<Option<usize>>) -> Self {
+		let mut new = self.clone();
+		new.max_instances = max_instances.into();
+		new
+	}
+
+	/// Returns the sample rate of the audio (in Hz).
+	#[must_use]
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Returns the number of frames in the [`CompressedSoundData`].
+	///
+	/// If [`CompressedSoundData::slice`] is `Some`, this will be the number
+	/// of frames in the slice.
+	#[must_use]
+	pub fn num_frames(&self) -> usize {
+		if let Some((start, end)) = self.slice {
+			end - start
+		} else {
+			self.total_num_frames
+		}
+	}
+
+	/// Returns the duration of the audio.
+	///
+	/// If [`CompressedSoundData::slice`] is `Some`, this will be the duration
+	/// of the slice.
+	#[must_use]
+	pub fn duration(&self) -> Duration {
+		Duration::from_secs_f64(self.num_frames() as f64 / self.sample_rate as f64)
+	}
+
+	/// Returns the size of the encoded audio (in bytes).
+	///
+	/// This memory is shared by all clones of the [`CompressedSoundData`].
+	#[must_use]
+	pub fn num_bytes(&self) -> usize {
+		self.bytes.len()
+	}
+
+	/// Returns the number of instances of this sound that are currently
+	/// playing, including instances played from clones of this
+	/// [`CompressedSoundData`].
+	#[must_use]
+	pub fn num_instances(&self) -> usize {
+		self.num_instances.load(Ordering::SeqCst)
+	}
+
+	/**
+	Sets the portion of the audio this [`CompressedSoundData`] represents.
+
+	This returns a cheap clone of the [`CompressedSoundData`] with the modified slice.
+	*/
+	#[must_use = "This method returns a modified CompressedSoundData and does not mutate the original value"]
+	pub fn slice(&self, region: impl IntoOptionalRegion) -> Self {
+		let mut new = self.clone();
+		new.slice = region.into_optional_region().map(|Region { start, end }| {
+			let start = start.into_samples(self.sample_rate);
+			let end = match end {
+				EndPosition::EndOfAudio => self.total_num_frames,
+				EndPosition::Custom(end) => end.into_samples(self.sample_rate),
+			};
+			(start, end)
+		});
+		new
+	}
+
+	#[allow(clippy::type_complexity)]
+	pub(super) fn split(
+		self,
+	) -> Result<
+		(
+			CompressedSound,
+			CompressedSoundHandle,
+			DecodeScheduler<FromFileError>,
+		),
+		PlayCompressedSoundError,
+	> {
+		self.num_instances
+			.fetch_update(
+				Ordering::SeqCst,
+				Ordering::SeqCst,
+				|num_instances| match self.max_instances {
+					Some(max_instances) if num_instances >= max_instances => None,
+					_ => Some(num_instances + 1),
+				},
+			)
+			.map_err(|_| PlayCompressedSoundError::InstanceLimitReached)?;
+		// each instance gets its own decoder reading from the shared bytes
+		let split = SymphoniaDecoder::new(Box::new(Cursor::new(self.bytes))).and_then(|decoder| {
+			StreamingSoundData {
+				decoder: Box::new(decoder),
+				settings: self.settings.into_streaming_settings(),
+				slice: self.slice,
+			}
+			.split_with_buffer_size(self.settings.buffer_size)
+		});
+		let (sound, handle, scheduler) = match split {
+			Ok(split) => split,
+			Err(error) => {
+				self.num_instances.fetch_sub(1, Ordering::SeqCst);
+				return Err(error.into());
+			}
+		};
+		Ok((
+			CompressedSound::new(sound, self.num_instances),
+			CompressedSoundHandle { handle },
+			scheduler,
+		))
+	}
+}
+
+impl SoundData for CompressedSoundData {
+	type Error = PlayCompressedSoundError;
+
+	type Handle = CompressedSoundHandle;
+
+	#[allow(clippy::type_complexity)]
+	fn into_sound(self) -> Result<(Box<dyn Sound>, Self::Handle), Self::Error> {
+		let (sound, handle, scheduler) = self.split()?;
+		decode_thread::start_decoding(scheduler);
+		Ok((Box::new(sound), handle))
+	}
+}
+
+impl Debug for CompressedSoundData {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("CompressedSoundData")
+			.field("sample_rate", &self.sample_rate)
+			.field(
+				"bytes",
+				&BytesDebug {
+					len: self.bytes.len(),
+				},
+			)
+			.field("settings", &self.settings)
+			.field("slice", &self.slice)
+			.field("max_instances", &self.max_instances)
+			.finish()
+	}
+}
+
+struct BytesDebug {
+	len: usize,
+}
+
+impl Debug for BytesDebug {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.write_fmt(format_args!("[{} bytes]", self.len))
+	}
+}
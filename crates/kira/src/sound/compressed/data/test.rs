@@ -0,0 +1,105 @@
+use std::{io::Cursor, time::Duration};
+
+use crate::{
+	manager::{
+		backend::mock::{MockBackend, MockBackendSettings},
+		error::PlaySoundError,
+		AudioManager, AudioManagerSettings,
+	},
+	sound::{
+		compressed::{wav::wav_bytes, PlayCompressedSoundError},
+		PlaybackState, SoundData,
+	},
+};
+
+use super::CompressedSoundData;
+
+#[test]
+fn duration() {
+	let data = test_data(4);
+	assert_eq!(data.num_frames(), 4);
+	assert_eq!(data.duration(), Duration::from_secs(4));
+}
+
+#[test]
+fn sliced_duration() {
+	let data = test_data(4).slice(2.0..3.0);
+	assert_eq!(data.num_frames(), 1);
+	assert_eq!(data.duration(), Duration::from_secs(1));
+}
+
+#[test]
+fn keeps_audio_encoded() {
+	let data = test_data(4);
+	assert_eq!(data.num_bytes(), wav_bytes(1, &[0.0; 4]).len());
+}
+
+/// Tests that clones of a `CompressedSoundData` share their
+/// count of playing instances.
+#[test]
+fn clones_share_num_instances() {
+	let data = test_data(4);
+	let clone = data.clone();
+	let (sound, _) = data.clone().into_sound().unwrap();
+	assert_eq!(data.num_instances(), 1);
+	assert_eq!(clone.num_instances(), 1);
+	drop(sound);
+	assert_eq!(data.num_instances(), 0);
+	assert_eq!(clone.num_instances(), 0);
+}
+
+/// Tests that a `CompressedSoundData` can't be played more times than
+/// `max_instances` allows, and that instances become available again
+/// once a sound is dropped.
+#[test]
+fn max_instances() {
+	let data = test_data(4).max_instances(2);
+	let (first, _) = data.clone().into_sound().unwrap();
+	let (_second, _) = data.clone().into_sound().unwrap();
+	assert!(matches!(
+		data.clone().into_sound(),
+		Err(PlayCompressedSoundError::InstanceLimitReached)
+	));
+	assert_eq!(data.num_instances(), 2);
+	drop(first);
+	assert!(data.clone().into_sound().is_ok());
+}
+
+/// Tests that an instance of a `CompressedSoundData` stops counting
+/// towards `max_instances` once it finishes playing, even though the
+/// finished sound isn't dropped until later.
+#[test]
+fn finished_instances_release_max_instances() {
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+		backend_settings: MockBackendSettings { sample_rate: 1 },
+		..Default::default()
+	})
+	.unwrap();
+	let data = test_data(4).max_instances(1);
+	let handle = manager.play(data.clone()).unwrap();
+	assert!(matches!(
+		manager.play(data.clone()),
+		Err(PlaySoundError::IntoSoundError(
+			PlayCompressedSoundError::InstanceLimitReached
+		))
+	));
+
+	// play the sound until it finishes. the decode thread
+	// runs in the background, so give it some time to catch up.
+	let backend = manager.backend_mut();
+	for _ in 0..1000 {
+		if handle.state() == PlaybackState::Stopped {
+			break;
+		}
+		backend.on_start_processing();
+		let _ = backend.process();
+		std::thread::sleep(Duration::from_millis(1));
+	}
+	assert_eq!(handle.state(), PlaybackState::Stopped);
+	assert_eq!(data.num_instances(), 0);
+	assert!(manager.play(data.clone()).is_ok());
+}
+
+fn test_data(num_samples: usize) -> CompressedSoundData {
+	CompressedSoundData::from_cursor(Cursor::new(wav_bytes(1, &vec![0.0; num_samples]))).unwrap()
+}
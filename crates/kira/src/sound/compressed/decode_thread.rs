@@ -0,0 +1,83 @@
+#[cfg(all(test, feature = "wav"))]
+mod test;
+
+use std::sync::{
+	mpsc::{channel, Receiver, SendError, Sender},
+	Mutex, PoisonError,
+};
+
+use crate::sound::{
+	streaming::sound::decode_scheduler::{
+		DecodeScheduler, NextStep, DECODER_THREAD_SLEEP_DURATION,
+	},
+	FromFileError,
+};
+
+/// Sends new compressed sounds to the decode thread.
+///
+/// `None` until the first compressed sound is played.
+static SCHEDULER_SENDER: Mutex<Option<Sender<DecodeScheduler<FromFileError>>>> = Mutex::new(None);
+
+/// Hands a compressed sound's [`DecodeScheduler`] to the thread that
+/// decodes audio for every playing compressed sound, starting the
+/// thread if it isn't running.
+pub(super) fn start_decoding(scheduler: DecodeScheduler<FromFileError>) {
+	let mut sender = SCHEDULER_SENDER
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner);
+	let scheduler = match &*sender {
+		Some(sender) => match sender.send(scheduler) {
+			Ok(()) => return,
+			// the thread is gone if a decoder panicked, so start a new one
+			Err(SendError(scheduler)) => scheduler,
+		},
+		None => scheduler,
+	};
+	let (new_sender, receiver) = channel();
+	std::thread::spawn(move || run(receiver));
+	new_sender
+		.send(scheduler)
+		.expect("the decode thread was just started");
+	*sender = Some(new_sender);
+}
+
+fn run(receiver: Receiver<DecodeScheduler<FromFileError>>) {
+	let mut schedulers = vec![];
+	loop {
+		// when no sounds are playing, sleep until a new one is played
+		if schedulers.is_empty() {
+			match receiver.recv() {
+				Ok(scheduler) => schedulers.push(scheduler),
+				Err(_) => return,
+			}
+		}
+		schedulers.extend(receiver.try_iter());
+		let decoded_audio = decode(&mut schedulers);
+		// if every sound's buffer is full, sleep for a bit
+		if !decoded_audio {
+			std::thread::sleep(DECODER_THREAD_SLEEP_DURATION);
+		}
+	}
+}
+
+/// Fills the buffer of each sound, removing the sounds that have
+/// finished decoding.
+///
+/// Returns `true` if any audio was decoded.
+fn decode(schedulers: &mut Vec<DecodeScheduler<FromFileError>>) -> bool {
+	let mut decoded_audio = false;
+	schedulers.retain_mut(|scheduler| loop {
+		match scheduler.run() {
+			Ok(NextStep::Continue) => decoded_audio = true,
+			Ok(NextStep::Wait) => return true,
+			Ok(NextStep::End) => return false,
+			// move on to the other sounds and try this one
+			// again next time
+			Err(error) => {
+				scheduler.report_error(error);
+				return true;
+			}
+		}
+	});
+	decoded_audio
+}
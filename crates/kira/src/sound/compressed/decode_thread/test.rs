@@ -0,0 +1,85 @@
+use std::io::Cursor;
+
+use crate::{
+	clock::clock_info::MockClockInfoProviderBuilder,
+	frame::Frame,
+	modulator::value_provider::MockModulatorValueProviderBuilder,
+	sound::{
+		compressed::{wav::wav_bytes, CompressedSoundData},
+		Sound,
+	},
+};
+
+use super::decode;
+
+/// Tests that the decode thread fills the buffer of every sound
+/// and stops decoding sounds that reached the end.
+#[test]
+fn decodes_every_sound() {
+	let data = test_data(&[1.0, 2.0, 3.0]);
+	let (mut sound_1, _, scheduler_1) = data.clone().split().unwrap();
+	let (mut sound_2, _, scheduler_2) = data.start_position(1.0).split().unwrap();
+	let mut schedulers = vec![scheduler_1, scheduler_2];
+
+	assert!(decode(&mut schedulers));
+	assert!(schedulers.is_empty());
+
+	for (sound, first_sample) in [(&mut sound_1, 1), (&mut sound_2, 2)] {
+		for i in first_sample..=3 {
+			assert_eq!(
+				sound.process(
+					1.0,
+					&MockClockInfoProviderBuilder::new(0).build(),
+					&MockModulatorValueProviderBuilder::new(0).build()
+				),
+				Frame::from_mono(i as f32).panned(0.5)
+			);
+		}
+	}
+}
+
+/// Tests that the decode thread only decodes as much audio as fits
+/// in a sound's buffer and continues once the sound has played some
+/// of it.
+#[test]
+fn waits_for_full_buffers() {
+	let data = test_data(&(1..=10).map(|i| i as f32).collect::<Vec<_>>()).buffer_size(4);
+	let (mut sound, _, scheduler) = data.split().unwrap();
+	let mut schedulers = vec![scheduler];
+
+	assert!(decode(&mut schedulers));
+	assert!(!decode(&mut schedulers));
+	assert_eq!(schedulers.len(), 1);
+
+	for i in 1..=10 {
+		decode(&mut schedulers);
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::from_mono(i as f32).panned(0.5)
+		);
+	}
+	assert!(schedulers.is_empty());
+}
+
+/// Tests that the decode thread stops decoding sounds that
+/// were dropped.
+#[test]
+fn removes_dropped_sounds() {
+	let data = test_data(&(1..=10).map(|i| i as f32).collect::<Vec<_>>()).buffer_size(4);
+	let (sound, _, scheduler) = data.split().unwrap();
+	let mut schedulers = vec![scheduler];
+
+	assert!(decode(&mut schedulers));
+	assert_eq!(schedulers.len(), 1);
+	drop(sound);
+	assert!(!decode(&mut schedulers));
+	assert!(schedulers.is_empty());
+}
+
+fn test_data(samples: &[f32]) -> CompressedSoundData {
+	CompressedSoundData::from_cursor(Cursor::new(wav_bytes(1, samples))).unwrap()
+}
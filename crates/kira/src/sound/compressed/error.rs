@@ -0,0 +1,40 @@
+use std::fmt::Display;
+
+use crate::sound::FromFileError;
+
+/// Errors that can occur when playing a compressed sound.
+#[derive(Debug)]
+pub enum PlayCompressedSoundError {
+	/// Could not play the sound because the maximum number of
+	/// instances of the [`CompressedSoundData`](super::CompressedSoundData)
+	/// are already playing.
+	InstanceLimitReached,
+	/// An error occurred when creating a decoder for the sound.
+	FromFileError(FromFileError),
+}
+
+impl Display for PlayCompressedSoundError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			PlayCompressedSoundError::InstanceLimitReached => f.write_str(
+				"Could not play the sound because the maximum number of instances are already playing",
+			),
+			PlayCompressedSoundError::FromFileError(error) => error.fmt(f),
+		}
+	}
+}
+
+impl std::error::Error for PlayCompressedSoundError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			PlayCompressedSoundError::FromFileError(error) => Some(error),
+			_ => None,
+		}
+	}
+}
+
+impl From<FromFileError> for PlayCompressedSoundError {
+	fn from(v: FromFileError) -> Self {
+		Self::FromFileError(v)
+	}
+}
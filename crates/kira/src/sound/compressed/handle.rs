@@ -0,0 +1,315 @@
+use crate::{
+	sound::{
+		streaming::StreamingSoundHandle, FromFileError, IntoOptionalRegion, PlaybackRate,
+		PlaybackState,
+	},
+	tween::{Tween, Value},
+	StartTime, Volume,
+};
+
+/// Controls a compressed sound.
+#[derive(Debug)]
+pub struct CompressedSoundHandle {
+	pub(super) handle: StreamingSoundHandle<FromFileError>,
+}
+
+impl CompressedSoundHandle {
+	/// Returns the current playback state of the sound.
+	#[must_use]
+	pub fn state(&self) -> PlaybackState {
+		self.handle.state()
+	}
+
+	/// Returns the current playback position of the sound (in seconds).
+	#[must_use]
+	pub fn position(&self) -> f64 {
+		self.handle.position()
+	}
+
+	/**
+	Sets the volume of the sound.
+
+	# Examples
+
+	Set the volume of the sound as a factor immediately:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	use kira::tween::Tween;
+
+	sound.set_volume(0.5, Tween::default());
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Smoothly transition the volume to a target value in decibels:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	use kira::tween::Tween;
+	use std::time::Duration;
+
+	sound.set_volume(kira::Volume::Decibels(-6.0), Tween {
+		duration: Duration::from_secs(3),
+		..Default::default()
+	});
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Link the volume to a modulator, smoothly transitioning from the current value:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+		modulator::tweener::TweenerBuilder,
+		tween::Tween,
+	};
+	use std::time::Duration;
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let tweener = manager.add_modulator(TweenerBuilder {
+		initial_value: 0.5,
+	})?;
+	let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	sound.set_volume(&tweener, Tween {
+		duration: Duration::from_secs(3),
+		..Default::default()
+	});
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn set_volume(&mut self, volume: impl Into<Value<Volume>>, tween: Tween) {
+		self.handle.set_volume(volume, tween)
+	}
+
+	/**
+	Sets the playback rate of the sound.
+
+	Changing the playback rate will change both the speed
+	and pitch of the sound. Compressed sounds cannot be played
+	backwards, so negative playback rates are treated as 0.
+
+	# Examples
+
+	Set the playback rate of the sound as a factor immediately:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	use kira::tween::Tween;
+
+	sound.set_playback_rate(0.5, Tween::default());
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Smoothly transition the playback rate to a target value in semitones:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	use kira::{
+		tween::Tween,
+		sound::PlaybackRate,
+	};
+	use std::time::Duration;
+
+	sound.set_playback_rate(PlaybackRate::Semitones(-2.0), Tween {
+		duration: Duration::from_secs(3),
+		..Default::default()
+	});
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Link the playback rate to a modulator, smoothly transitioning from the current value:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+		modulator::tweener::TweenerBuilder,
+		tween::Tween,
+	};
+	use std::time::Duration;
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let tweener = manager.add_modulator(TweenerBuilder {
+		initial_value: 0.5,
+	})?;
+	let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	sound.set_playback_rate(&tweener, Tween {
+		duration: Duration::from_secs(3),
+		..Default::default()
+	});
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn set_playback_rate(
+		&mut self,
+		playback_rate: impl Into<Value<PlaybackRate>>,
+		tween: Tween,
+	) {
+		self.handle.set_playback_rate(playback_rate, tween)
+	}
+
+	/**
+	Sets the panning of the sound, where `0.0` is hard left,
+	`0.5` is center, and `1.0` is hard right.
+
+	# Examples
+
+	Smoothly transition the panning to a target value:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	use kira::tween::Tween;
+	use std::time::Duration;
+
+	sound.set_panning(0.25, Tween {
+		duration: Duration::from_secs(3),
+		..Default::default()
+	});
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Link the panning to a modulator, smoothly transitioning from the current value:
+
+	```no_run
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+		modulator::tweener::TweenerBuilder,
+		tween::Tween,
+	};
+	use std::time::Duration;
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let tweener = manager.add_modulator(TweenerBuilder {
+		initial_value: 0.25,
+	})?;
+	let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	sound.set_panning(&tweener, Tween {
+		duration: Duration::from_secs(3),
+		..Default::default()
+	});
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn set_panning(&mut self, panning: impl Into<Value<f64>>, tween: Tween) {
+		self.handle.set_panning(panning, tween)
+	}
+
+	/**
+	Sets the portion of the sound that will play in a loop.
+
+	# Examples
+
+	Set the sound to loop the portion from 3 seconds in to the end:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	sound.set_loop_region(3.0..);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Set the sound to loop the portion from 2 to 4 seconds:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	sound.set_loop_region(2.0..4.0);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+
+	Set a sound that was previously looping to stop looping:
+
+	```no_run
+	# use kira::{
+	# 	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	# 	sound::compressed::{CompressedSoundData, CompressedSoundSettings},
+	# };
+	# let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	# let mut sound = manager.play(CompressedSoundData::from_file("sound.ogg")?)?;
+	sound.set_loop_region(None);
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	pub fn set_loop_region(&mut self, loop_region: impl IntoOptionalRegion) {
+		self.handle.set_loop_region(loop_region)
+	}
+
+	/// Fades out the sound to silence with the given tween and then
+	/// pauses playback.
+	pub fn pause(&mut self, tween: Tween) {
+		self.handle.pause(tween)
+	}
+
+	/// Resumes playback and fades in the sound from silence
+	/// with the given tween.
+	pub fn resume(&mut self, tween: Tween) {
+		self.resume_at(StartTime::Immediate, tween)
+	}
+
+	/// Resumes playback at the given start time and fades in
+	/// the sound from silence with the given tween.
+	pub fn resume_at(&mut self, start_time: StartTime, tween: Tween) {
+		self.handle.resume_at(start_time, tween)
+	}
+
+	/// Fades out the sound to silence with the given tween and then
+	/// stops playback.
+	///
+	/// Once the sound is stopped, it cannot be restarted.
+	pub fn stop(&mut self, tween: Tween) {
+		self.handle.stop(tween)
+	}
+
+	/// Sets the playback position to the specified time in seconds.
+	pub fn seek_to(&mut self, position: f64) {
+		self.handle.seek_to(position)
+	}
+
+	/// Moves the playback position by the specified amount of time in seconds.
+	pub fn seek_by(&mut self, amount: f64) {
+		self.handle.seek_by(amount)
+	}
+
+	/// Returns an error that occurred while decoding audio, if any.
+	///
+	/// If an error occurs, the sound will stop playing.
+	#[must_use]
+	pub fn pop_error(&mut self) -> Option<FromFileError> {
+		self.handle.pop_error()
+	}
+}
@@ -0,0 +1,179 @@
+use crate::{
+	sound::{
+		streaming::StreamingSoundSettings, IntoOptionalRegion, PlaybackPosition, PlaybackRate,
+		Region,
+	},
+	tween::{Tween, Value},
+	OutputDestination, StartTime, Volume,
+};
+
+const DEFAULT_BUFFER_SIZE: usize = 2048;
+
+/// Settings for a compressed sound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompressedSoundSettings {
+	/// When the sound should start playing.
+	pub start_time: StartTime,
+	/// Where in the sound playback should start.
+	pub start_position: PlaybackPosition,
+	/// The portion of the sound that should be looped.
+	pub loop_region: Option<Region>,
+	/// The volume of the sound.
+	pub volume: Value<Volume>,
+	/// The playback rate of the sound.
+	///
+	/// Changing the playback rate will change both the speed
+	/// and the pitch of the sound. Compressed sounds cannot be
+	/// played backwards, so negative playback rates are treated
+	/// as 0.
+	pub playback_rate: Value<PlaybackRate>,
+	/// The panning of the sound, where 0 is hard left
+	/// and 1 is hard right.
+	pub panning: Value<f64>,
+	/// The destination that this sound should be routed to.
+	pub output_destination: OutputDestination,
+	/// An optional fade-in from silence.
+	pub fade_in_tween: Option<Tween>,
+	/// How many frames of audio each instance of the sound can
+	/// decode ahead of the playback position.
+	///
+	/// Larger buffers make it less likely for the sound to cut out
+	/// when the decode thread is busy, but they use more memory for
+	/// every playing instance.
+	pub buffer_size: usize,
+}
+
+impl CompressedSoundSettings {
+	/// Creates a new [`CompressedSoundSettings`] with the default settings.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			start_time: StartTime::default(),
+			start_position: PlaybackPosition::Seconds(0.0),
+			loop_region: None,
+			volume: Value::Fixed(Volume::Amplitude(1.0)),
+			playback_rate: Value::Fixed(PlaybackRate::Factor(1.0)),
+			panning: Value::Fixed(0.5),
+			output_destination: OutputDestination::default(),
+			fade_in_tween: None,
+			buffer_size: DEFAULT_BUFFER_SIZE,
+		}
+	}
+
+	/** Sets when the sound should start playing. */
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn start_time(self, start_time: impl Into<StartTime>) -> Self {
+		Self {
+			start_time: start_time.into(),
+			..self
+		}
+	}
+
+	/// Sets where in the sound playback should start.
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn start_position(self, start_position: impl Into<PlaybackPosition>) -> Self {
+		Self {
+			start_position: start_position.into(),
+			..self
+		}
+	}
+
+	/** Sets the portion of the sound that should be looped. */
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn loop_region(self, loop_region: impl IntoOptionalRegion) -> Self {
+		Self {
+			loop_region: loop_region.into_optional_region(),
+			..self
+		}
+	}
+
+	/** Sets the volume of the sound. */
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn volume(self, volume: impl Into<Value<Volume>>) -> Self {
+		Self {
+			volume: volume.into(),
+			..self
+		}
+	}
+
+	/**
+	Sets the playback rate of the sound.
+
+	Changing the playback rate will change both the speed
+	and the pitch of the sound. Compressed sounds cannot be
+	played backwards, so negative playback rates are treated
+	as 0.
+	*/
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn playback_rate(self, playback_rate: impl Into<Value<PlaybackRate>>) -> Self {
+		Self {
+			playback_rate: playback_rate.into(),
+			..self
+		}
+	}
+
+	/**
+	Sets the panning of the sound, where 0 is hard left
+	and 1 is hard right.
+	*/
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn panning(self, panning: impl Into<Value<f64>>) -> Self {
+		Self {
+			panning: panning.into(),
+			..self
+		}
+	}
+
+	/**
+	Sets the destination that this sound should be routed to.
+	*/
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn output_destination(self, output_destination: impl Into<OutputDestination>) -> Self {
+		Self {
+			output_destination: output_destination.into(),
+			..self
+		}
+	}
+
+	/// Sets the tween used to fade in the sound from silence.
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn fade_in_tween(self, fade_in_tween: impl Into<Option<Tween>>) -> Self {
+		Self {
+			fade_in_tween: fade_in_tween.into(),
+			..self
+		}
+	}
+
+	/// Sets how many frames of audio each instance of the sound can
+	/// decode ahead of the playback position.
+	#[must_use = "This method consumes self and returns a modified CompressedSoundSettings, so the return value should be used"]
+	pub fn buffer_size(self, buffer_size: usize) -> Self {
+		Self {
+			buffer_size,
+			..self
+		}
+	}
+
+	/// Returns the settings for the streaming sound that plays
+	/// back the decoded audio.
+	#[must_use]
+	pub(super) fn into_streaming_settings(self) -> StreamingSoundSettings {
+		StreamingSoundSettings {
+			start_time: self.start_time,
+			start_position: self.start_position,
+			loop_region: self.loop_region,
+			volume: self.volume,
+			playback_rate: self.playback_rate,
+			panning: self.panning,
+			output_destination: self.output_destination,
+			fade_in_tween: self.fade_in_tween,
+			..StreamingSoundSettings::default()
+		}
+	}
+}
+
+impl Default for CompressedSoundSettings {
+	fn default() -> Self {
+		Self::new()
+	}
+}
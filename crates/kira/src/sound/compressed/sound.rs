@@ -0,0 +1,92 @@
+#[cfg(all(test, feature = "wav"))]
+mod test;
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+use crate::{
+	clock::clock_info::ClockInfoProvider,
+	frame::Frame,
+	modulator::value_provider::ModulatorValueProvider,
+	sound::{streaming::sound::StreamingSound, Sound},
+	OutputDestination,
+};
+
+/// Plays back audio decoded by a background thread and keeps track
+/// of how many instances of a [`CompressedSoundData`](super::CompressedSoundData)
+/// are playing.
+pub(super) struct CompressedSound {
+	sound: StreamingSound,
+	num_instances: Arc<AtomicUsize>,
+	released_instance: bool,
+}
+
+impl CompressedSound {
+	/// Creates a new [`CompressedSound`].
+	///
+	/// `num_instances` should already have been incremented for this sound.
+	/// It will be decremented when the sound stops or is dropped,
+	/// whichever happens first.
+	#[must_use]
+	pub fn new(sound: StreamingSound, num_instances: Arc<AtomicUsize>) -> Self {
+		Self {
+			sound,
+			num_instances,
+			released_instance: false,
+		}
+	}
+
+	/// Makes this sound's slot available to new instances.
+	///
+	/// Finished sounds aren't dropped until the next time a resource
+	/// is added on the gameplay thread, which happens after
+	/// [`CompressedSoundData::max_instances`](super::CompressedSoundData::max_instances)
+	/// is checked, so the slot has to be released as soon as the sound stops.
+	fn release_instance(&mut self) {
+		if self.released_instance {
+			return;
+		}
+		self.released_instance = true;
+		self.num_instances.fetch_sub(1, Ordering::SeqCst);
+	}
+}
+
+impl Sound for CompressedSound {
+	fn output_destination(&mut self) -> OutputDestination {
+		self.sound.output_destination()
+	}
+
+	fn on_start_processing(&mut self) {
+		self.sound.on_start_processing();
+	}
+
+	fn process(
+		&mut self,
+		dt: f64,
+		clock_info_provider: &ClockInfoProvider,
+		modulator_value_provider: &ModulatorValueProvider,
+	) -> Frame {
+		let out = self
+			.sound
+			.process(dt, clock_info_provider, modulator_value_provider);
+		if self.sound.finished() {
+			self.release_instance();
+		}
+		out
+	}
+
+	fn finished(&self) -> bool {
+		self.sound.finished()
+	}
+}
+
+impl Drop for CompressedSound {
+	fn drop(&mut self) {
+		// lets the decode thread know it can stop decoding this sound if it was
+		// dropped before it finished, like when the manager is dropped
+		self.sound.stop_immediately();
+		self.release_instance();
+	}
+}
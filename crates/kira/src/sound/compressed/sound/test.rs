@@ -0,0 +1,261 @@
+use std::io::Cursor;
+
+use crate::{
+	clock::clock_info::MockClockInfoProviderBuilder,
+	frame::Frame,
+	modulator::value_provider::MockModulatorValueProviderBuilder,
+	sound::{
+		compressed::{wav::wav_bytes, CompressedSoundData, CompressedSoundSettings},
+		streaming::sound::decode_scheduler::{DecodeScheduler, NextStep},
+		FromFileError, PlaybackState, Sound,
+	},
+	tween::Tween,
+};
+
+use super::CompressedSound;
+
+/// Tests that a `CompressedSound` will play all of its samples before finishing.
+#[test]
+fn plays_all_samples() {
+	let data = test_data(&[1.0, 2.0, 3.0], CompressedSoundSettings::new());
+	let (mut sound, handle, mut scheduler) = data.split().unwrap();
+	while matches!(scheduler.run().unwrap(), NextStep::Continue) {}
+
+	assert!(!sound.finished());
+
+	for i in 1..=3 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::from_mono(i as f32).panned(0.5)
+		);
+		assert!(!sound.finished());
+	}
+
+	// give some time for the resample buffer to empty. in the meantime we should
+	// get silent output.
+	for _ in 0..10 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::from_mono(0.0).panned(0.5)
+		);
+	}
+
+	// the sound should be finished and stopped by now
+	assert!(sound.finished());
+	assert_eq!(handle.state(), PlaybackState::Stopped);
+}
+
+/// Tests that a `CompressedSound` can be played partially.
+#[test]
+#[allow(clippy::float_cmp)]
+fn start_position() {
+	let data = test_data(
+		&ascending_samples(10),
+		CompressedSoundSettings::new().start_position(3.0),
+	);
+	let (mut sound, handle, mut scheduler) = data.split().unwrap();
+	while matches!(scheduler.run().unwrap(), NextStep::Continue) {}
+
+	for i in 3..=6 {
+		assert_eq!(handle.position(), i as f64);
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::from_mono(i as f32).panned(0.5)
+		);
+		sound.on_start_processing();
+	}
+}
+
+/// Tests that a `CompressedSound` loops correctly when the loop
+/// region spans many decoded packets.
+#[test]
+fn loops_across_packets() {
+	const NUM_SAMPLES: usize = 50_000;
+	let data = test_data(
+		&ascending_samples(NUM_SAMPLES),
+		CompressedSoundSettings::new().loop_region(10.0..),
+	);
+	let (mut sound, _, mut scheduler) = data.split().unwrap();
+	for _ in 0..NUM_SAMPLES {
+		scheduler.run().unwrap();
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+	expect_frame_soon(
+		Frame::from_mono(10.0).panned(0.5),
+		&mut sound,
+		&mut scheduler,
+	);
+	expect_frame_soon(
+		Frame::from_mono(15.0).panned(0.5),
+		&mut sound,
+		&mut scheduler,
+	);
+}
+
+/// Tests that a `CompressedSound` can seek to a position far
+/// ahead of what has been decoded so far.
+#[test]
+fn seek_to_far_ahead() {
+	let data = test_data(&ascending_samples(100_000), CompressedSoundSettings::new());
+	let (mut sound, mut handle, mut scheduler) = data.split().unwrap();
+	handle.seek_to(90_000.0);
+	sound.on_start_processing();
+	expect_frame_soon(
+		Frame::from_mono(90_000.0).panned(0.5),
+		&mut sound,
+		&mut scheduler,
+	);
+}
+
+/// Tests that a sliced `CompressedSound` only plays the
+/// specified portion of the audio.
+#[test]
+fn slice() {
+	let data = test_data(&ascending_samples(10), CompressedSoundSettings::new()).slice(3.0..6.0);
+	let (mut sound, _, mut scheduler) = data.split().unwrap();
+	while matches!(scheduler.run().unwrap(), NextStep::Continue) {}
+
+	for i in 3..6 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::from_mono(i as f32).panned(0.5)
+		);
+	}
+	assert_eq!(
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build()
+		),
+		Frame::from_mono(0.0).panned(0.5)
+	);
+}
+
+/// Tests that a `CompressedSound` holds its position instead of
+/// playing backwards when its playback rate becomes negative.
+#[test]
+#[allow(clippy::float_cmp)]
+fn negative_playback_rate_holds_position() {
+	let data = test_data(&ascending_samples(10), CompressedSoundSettings::new());
+	let (mut sound, mut handle, mut scheduler) = data.clone().split().unwrap();
+	while matches!(scheduler.run().unwrap(), NextStep::Continue) {}
+
+	for _ in 0..2 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+	sound.on_start_processing();
+	assert_eq!(handle.position(), 2.0);
+
+	handle.set_playback_rate(-1.0, Tween::default());
+	sound.on_start_processing();
+	for _ in 0..3 {
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+	}
+	sound.on_start_processing();
+	assert!(!sound.finished());
+	assert_eq!(handle.state(), PlaybackState::Playing);
+	assert_eq!(handle.position(), 2.0);
+	assert_eq!(data.num_instances(), 1);
+
+	handle.set_playback_rate(1.0, Tween::default());
+	sound.on_start_processing();
+	expect_frame_soon(
+		Frame::from_mono(3.0).panned(0.5),
+		&mut sound,
+		&mut scheduler,
+	);
+}
+
+/// Tests that the decode thread for a `CompressedSound` ends
+/// when the sound is dropped before it finishes.
+#[test]
+fn dropping_sound_ends_decoding() {
+	let data = test_data(&ascending_samples(10), CompressedSoundSettings::new());
+	let (sound, _, mut scheduler) = data.split().unwrap();
+	assert!(matches!(scheduler.run().unwrap(), NextStep::Continue));
+	drop(sound);
+	assert!(matches!(scheduler.run().unwrap(), NextStep::End));
+}
+
+/// Tests that playing a `CompressedSound` doesn't allocate, since
+/// all of the decoding happens on the decode thread.
+#[cfg(feature = "assert_no_alloc")]
+#[test]
+fn does_not_allocate() {
+	const NUM_SAMPLES: usize = 50_000;
+	let data = test_data(
+		&ascending_samples(NUM_SAMPLES),
+		CompressedSoundSettings::new().loop_region(..),
+	);
+	let (mut sound, _, mut scheduler) = data.split().unwrap();
+	let clock_info_provider = MockClockInfoProviderBuilder::new(0).build();
+	let modulator_value_provider = MockModulatorValueProviderBuilder::new(0).build();
+	for _ in 0..NUM_SAMPLES * 2 {
+		scheduler.run().unwrap();
+		assert_no_alloc::assert_no_alloc(|| {
+			sound.on_start_processing();
+			sound.process(1.0, &clock_info_provider, &modulator_value_provider);
+		});
+	}
+}
+
+fn test_data(samples: &[f32], settings: CompressedSoundSettings) -> CompressedSoundData {
+	CompressedSoundData::from_cursor(Cursor::new(wav_bytes(1, samples)))
+		.unwrap()
+		.with_settings(settings)
+}
+
+fn ascending_samples(num_samples: usize) -> Vec<f32> {
+	(0..num_samples).map(|i| i as f32).collect()
+}
+
+fn expect_frame_soon(
+	expected_frame: Frame,
+	sound: &mut CompressedSound,
+	scheduler: &mut DecodeScheduler<FromFileError>,
+) {
+	const NUM_SAMPLES_TO_WAIT: usize = 10;
+	for _ in 0..NUM_SAMPLES_TO_WAIT {
+		scheduler.run().unwrap();
+		let frame = sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build(),
+		);
+		if frame == expected_frame {
+			return;
+		}
+	}
+	panic!(
+		"Sound did not output frame with value {:?} within {} samples",
+		expected_frame, NUM_SAMPLES_TO_WAIT
+	);
+}
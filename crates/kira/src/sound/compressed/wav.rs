@@ -0,0 +1,28 @@
+/// Encodes mono samples as a 32-bit float WAV file so compressed
+/// sounds can be tested without any files on disk.
+#[must_use]
+pub(crate) fn wav_bytes(sample_rate: u32, samples: &[f32]) -> Vec<u8> {
+	const FORMAT_IEEE_FLOAT: u16 = 3;
+	const NUM_CHANNELS: u16 = 1;
+	const BYTES_PER_SAMPLE: u16 = 4;
+	let data_len = samples.len() as u32 * BYTES_PER_SAMPLE as u32;
+	let mut bytes = vec![];
+	bytes.extend_from_slice(b"RIFF");
+	bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+	bytes.extend_from_slice(b"WAVE");
+	bytes.extend_from_slice(b"fmt ");
+	bytes.extend_from_slice(&16u32.to_le_bytes());
+	bytes.extend_from_slice(&FORMAT_IEEE_FLOAT.to_le_bytes());
+	bytes.extend_from_slice(&NUM_CHANNELS.to_le_bytes());
+	bytes.extend_from_slice(&sample_rate.to_le_bytes());
+	bytes
+		.extend_from_slice(&(sample_rate * (NUM_CHANNELS * BYTES_PER_SAMPLE) as u32).to_le_bytes());
+	bytes.extend_from_slice(&(NUM_CHANNELS * BYTES_PER_SAMPLE).to_le_bytes());
+	bytes.extend_from_slice(&(BYTES_PER_SAMPLE * 8).to_le_bytes());
+	bytes.extend_from_slice(b"data");
+	bytes.extend_from_slice(&data_len.to_le_bytes());
+	for sample in samples {
+		bytes.extend_from_slice(&sample.to_le_bytes());
+	}
+	bytes
+}
@@ -0,0 +1,139 @@
+use std::{
+	fmt::{Debug, Formatter},
+	sync::Arc,
+};
+
+use crate::{
+	clock::clock_info::ClockInfoProvider, frame::Frame,
+	modulator::value_provider::ModulatorValueProvider,
+};
+
+/**
+Modifies the output of an individual sound.
+
+A sound processor works like an [`Effect`](crate::effect::Effect),
+but instead of processing the audio of a whole mixer track, it processes
+the audio of a single playing sound after the sound's volume, panning,
+and playback rate have been applied.
+
+Each time a sound is played, a new processor is created for that
+instance of the sound.
+
+For performance reasons, avoid allocating and deallocating in any methods
+of this trait besides [`init`](SoundProcessor::init) and
+[`on_change_sample_rate`](SoundProcessor::on_change_sample_rate).
+
+# Examples
+
+A bit crusher that reduces the resolution of the sound's samples:
+
+```no_run
+use kira::{
+	clock::clock_info::ClockInfoProvider,
+	manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+	modulator::value_provider::ModulatorValueProvider,
+	sound::{
+		static_sound::{StaticSoundData, StaticSoundSettings},
+		SoundProcessor,
+	},
+	Frame,
+};
+
+struct BitCrusher {
+	num_levels: f32,
+}
+
+impl BitCrusher {
+	fn new(bit_depth: u32) -> Self {
+		Self {
+			num_levels: 2.0f32.powi(bit_depth as i32 - 1),
+		}
+	}
+
+	fn crush(&self, sample: f32) -> f32 {
+		(sample * self.num_levels).round() / self.num_levels
+	}
+}
+
+impl SoundProcessor for BitCrusher {
+	fn process(
+		&mut self,
+		input: Frame,
+		_dt: f64,
+		_clock_info_provider: &ClockInfoProvider,
+		_modulator_value_provider: &ModulatorValueProvider,
+	) -> Frame {
+		Frame::new(self.crush(input.left), self.crush(input.right))
+	}
+}
+
+let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+let sound = StaticSoundData::from_file("sound.ogg")?
+	.with_settings(StaticSoundSettings::new().with_processor(|| BitCrusher::new(4)));
+manager.play(sound)?;
+# Result::<(), Box<dyn std::error::Error>>::Ok(())
+```
+*/
+#[allow(unused_variables)]
+pub trait SoundProcessor: Send {
+	/// Called once when the sound is played, before it is sent to the
+	/// renderer.
+	fn init(&mut self, sample_rate: u32) {}
+
+	/// Called when the sample rate of the renderer is changed.
+	fn on_change_sample_rate(&mut self, sample_rate: u32) {}
+
+	/// Called whenever a new batch of audio samples is requested by the backend.
+	///
+	/// This is a good place to put code that needs to run fairly frequently,
+	/// but not for every single audio sample.
+	fn on_start_processing(&mut self) {}
+
+	/// Transforms an output [`Frame`] of the sound.
+	///
+	/// `dt` is the time that's elapsed since the previous round of
+	/// processing (in seconds).
+	fn process(
+		&mut self,
+		input: Frame,
+		dt: f64,
+		clock_info_provider: &ClockInfoProvider,
+		modulator_value_provider: &ModulatorValueProvider,
+	) -> Frame;
+}
+
+/// Creates a new [`SoundProcessor`] every time a sound is played.
+///
+/// These can be cheaply cloned. Two factories are equal if one
+/// is a clone of the other.
+#[derive(Clone)]
+pub struct SoundProcessorFactory(Arc<dyn Fn() -> Box<dyn SoundProcessor> + Send + Sync>);
+
+impl SoundProcessorFactory {
+	/// Creates a [`SoundProcessorFactory`] that calls `make_processor`
+	/// to create each processor.
+	#[must_use]
+	pub fn new<P: SoundProcessor + 'static>(
+		make_processor: impl Fn() -> P + Send + Sync + 'static,
+	) -> Self {
+		Self(Arc::new(move || Box::new(make_processor())))
+	}
+
+	/// Creates a new [`SoundProcessor`].
+	#[must_use]
+	pub fn create(&self) -> Box<dyn SoundProcessor> {
+		(self.0)()
+	}
+}
+
+impl PartialEq for SoundProcessorFactory {
+	fn eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.0, &other.0)
+	}
+}
+
+impl Debug for SoundProcessorFactory {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("SoundProcessorFactory").finish()
+	}
+}
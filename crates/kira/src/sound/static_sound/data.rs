@@ -14,6 +14,7 @@ use crate::{
 	frame::Frame,
 	sound::{
 		EndPosition, IntoOptionalRegion, PlaybackPosition, PlaybackRate, Region, Sound, SoundData,
+		SoundProcessor, SoundProcessorFactory,
 	},
 	tween::{Tween, Value},
 	OutputDestination, StartTime, Volume,
@@ -317,6 +318,19 @@ impl StaticSoundData {
 		new
 	}
 
+	/// Sets a function that creates a [`SoundProcessor`] for each instance of the sound.
+	///
+	/// This returns a cheap clone of the [`StaticSoundData`] with the modified processor.
+	#[must_use = "This method returns a modified StaticSoundData and does not mutate the original value"]
+	pub fn with_processor<P: SoundProcessor + 'static>(
+		&self,
+		make_processor: impl Fn() -> P + Send + Sync + 'static,
+	) -> Self {
+		let mut new = self.clone();
+		new.settings.processor = Some(SoundProcessorFactory::new(make_processor));
+		new
+	}
+
 	/// Returns a cheap clone of the `StaticSoundData` with the specified settings.
 	#[must_use = "This method returns a modified StaticSoundData and does not mutate the original value"]
 	pub fn with_settings(&self, settings: StaticSoundSettings) -> Self {
@@ -1,11 +1,14 @@
 use crate::{
-	sound::{IntoOptionalRegion, PlaybackPosition, PlaybackRate, Region},
+	sound::{
+		IntoOptionalRegion, PlaybackPosition, PlaybackRate, Region, SoundProcessor,
+		SoundProcessorFactory,
+	},
 	tween::{Tween, Value},
 	OutputDestination, StartTime, Volume,
 };
 
 /// Settings for a static sound.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StaticSoundSettings {
 	/// When the sound should start playing.
 	pub start_time: StartTime,
@@ -29,6 +32,9 @@ pub struct StaticSoundSettings {
 	pub output_destination: OutputDestination,
 	/// An optional fade-in from silence.
 	pub fade_in_tween: Option<Tween>,
+	/// Creates a [`SoundProcessor`] to modify the output of
+	/// each instance of the sound.
+	pub processor: Option<SoundProcessorFactory>,
 }
 
 impl StaticSoundSettings {
@@ -45,6 +51,7 @@ impl StaticSoundSettings {
 			panning: Value::Fixed(0.5),
 			output_destination: OutputDestination::default(),
 			fade_in_tween: None,
+			processor: None,
 		}
 	}
 
@@ -135,6 +142,22 @@ impl StaticSoundSettings {
 			..self
 		}
 	}
+
+	/**
+	Sets a function that creates a [`SoundProcessor`] for each instance of the sound.
+
+	The function is called on the gameplay thread every time the sound is played.
+	*/
+	#[must_use = "This method consumes self and returns a modified StaticSoundSettings, so the return value should be used"]
+	pub fn with_processor<P: SoundProcessor + 'static>(
+		self,
+		make_processor: impl Fn() -> P + Send + Sync + 'static,
+	) -> Self {
+		Self {
+			processor: Some(SoundProcessorFactory::new(make_processor)),
+			..self
+		}
+	}
 }
 
 impl Default for StaticSoundSettings {
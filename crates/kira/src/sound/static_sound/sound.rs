@@ -15,7 +15,7 @@ use crate::{
 	modulator::value_provider::ModulatorValueProvider,
 	sound::{
 		transport::Transport, util::create_volume_fade_parameter, PlaybackRate, PlaybackState,
-		Sound,
+		Sound, SoundProcessor,
 	},
 	tween::{Parameter, Tween, Value},
 	OutputDestination, StartTime, Volume,
@@ -44,12 +44,12 @@ pub(super) struct StaticSound {
 	volume_fade_start_time: StartTime,
 	resume_queued: bool,
 	shared: Arc<Shared>,
+	processor: Option<Box<dyn SoundProcessor>>,
 }
 
 impl StaticSound {
 	#[must_use]
 	pub fn new(data: StaticSoundData, command_readers: CommandReaders) -> Self {
-		let settings = data.settings;
 		let transport = Transport::new(
 			data.settings.start_position.into_samples(data.sample_rate),
 			data.settings.loop_region,
@@ -57,6 +57,7 @@ impl StaticSound {
 			data.sample_rate,
 			data.num_frames(),
 		);
+		let settings = data.settings;
 		let starting_frame_index = transport.position;
 		let position = starting_frame_index as f64 / data.sample_rate as f64;
 		let mut sound = Self {
@@ -64,8 +65,8 @@ impl StaticSound {
 			sample_rate: data.sample_rate,
 			frames: data.frames,
 			slice: data.slice,
-			reverse: data.settings.reverse,
-			output_destination: data.settings.output_destination,
+			reverse: settings.reverse,
+			output_destination: settings.output_destination,
 			state: PlaybackState::Playing,
 			start_time: settings.start_time,
 			resampler: Resampler::new(starting_frame_index),
@@ -81,6 +82,7 @@ impl StaticSound {
 				state: AtomicU8::new(PlaybackState::Playing as u8),
 				position: AtomicU64::new(position.to_bits()),
 			}),
+			processor: settings.processor.map(|processor| processor.create()),
 		};
 		// fill the resample buffer with 3 samples so playback can
 		// start immediately
@@ -221,6 +223,18 @@ impl Sound for StaticSound {
 		self.output_destination
 	}
 
+	fn init(&mut self, sample_rate: u32) {
+		if let Some(processor) = &mut self.processor {
+			processor.init(sample_rate);
+		}
+	}
+
+	fn on_change_sample_rate(&mut self, sample_rate: u32) {
+		if let Some(processor) = &mut self.processor {
+			processor.on_change_sample_rate(sample_rate);
+		}
+	}
+
 	fn on_start_processing(&mut self) {
 		let last_played_frame_position = self.resampler.current_frame_index();
 		self.shared.position.store(
@@ -228,6 +242,9 @@ impl Sound for StaticSound {
 			Ordering::SeqCst,
 		);
 		self.read_commands();
+		if let Some(processor) = &mut self.processor {
+			processor.on_start_processing();
+		}
 	}
 
 	fn process(
@@ -270,13 +287,16 @@ impl Sound for StaticSound {
 		}
 
 		// play back audio
-		let out = self.resampler.get(self.fractional_position as f32);
+		let mut out = self.resampler.get(self.fractional_position as f32);
 		self.fractional_position +=
 			self.sample_rate as f64 * self.playback_rate.value().as_factor().abs() * dt;
 		while self.fractional_position >= 1.0 {
 			self.fractional_position -= 1.0;
 			self.update_position();
 		}
+		if let Some(processor) = &mut self.processor {
+			out = processor.process(out, dt, clock_info_provider, modulator_value_provider);
+		}
 		out
 	}
 
@@ -1,12 +1,15 @@
 use std::{sync::Arc, time::Duration};
 
 use crate::{
-	clock::{clock_info::MockClockInfoProviderBuilder, ClockTime},
+	clock::{
+		clock_info::{ClockInfoProvider, MockClockInfoProviderBuilder},
+		ClockTime,
+	},
 	frame::Frame,
-	modulator::value_provider::MockModulatorValueProviderBuilder,
+	modulator::value_provider::{MockModulatorValueProviderBuilder, ModulatorValueProvider},
 	sound::{
 		static_sound::{StaticSoundData, StaticSoundSettings},
-		PlaybackState, Sound,
+		PlaybackState, Sound, SoundProcessor,
 	},
 	tween::Tween,
 	StartTime, Volume,
@@ -729,6 +732,45 @@ fn volume() {
 	);
 }
 
+/// Tests that a `StaticSound`'s processor is applied to the output
+/// after the volume and panning.
+#[test]
+#[allow(clippy::float_cmp)]
+fn processor() {
+	struct AddOne;
+
+	impl SoundProcessor for AddOne {
+		fn process(
+			&mut self,
+			input: Frame,
+			_dt: f64,
+			_clock_info_provider: &ClockInfoProvider,
+			_modulator_value_provider: &ModulatorValueProvider,
+		) -> Frame {
+			input + Frame::from_mono(1.0)
+		}
+	}
+
+	let data = StaticSoundData {
+		sample_rate: 1,
+		frames: Arc::new([Frame::from_mono(1.0); 10]),
+		settings: StaticSoundSettings::new()
+			.volume(0.5)
+			.with_processor(|| AddOne),
+		slice: None,
+	};
+	let (mut sound, _) = data.split();
+
+	assert_eq!(
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build()
+		),
+		Frame::from_mono(0.5).panned(0.5) + Frame::from_mono(1.0)
+	);
+}
+
 /// Tests that the volume of a `StaticSound` can be changed
 /// after the sound is started.
 #[test]
@@ -20,19 +20,25 @@ manager.play(sound_data)?;
 ```
 
 Streaming sounds use less memory than static sounds, but they use more
-CPU, and they can have delays when starting or seeking.
+CPU, and they can have delays when starting or seeking. To start a streaming
+sound on time, give it a [`StartTime::ClockTime`]
+far enough in the future for the audio to be decoded, and use
+[`StreamingSoundSettings::start_when_buffered`] to choose how much audio
+should be ready before playback begins.
 */
 
 #![cfg_attr(docsrs, doc(cfg(not(wasm32))))]
 
 mod data;
-mod decoder;
+pub(crate) mod decoder;
+mod event;
 mod handle;
 mod settings;
-mod sound;
+pub(crate) mod sound;
 
 pub use data::*;
 pub use decoder::*;
+pub use event::*;
 pub use handle::*;
 pub use settings::*;
 
@@ -15,10 +15,14 @@ use super::{command_writers_and_readers, StreamingSoundHandle, StreamingSoundSet
 
 use super::{
 	decoder::Decoder,
-	sound::{decode_scheduler::DecodeScheduler, StreamingSound},
+	sound::{
+		decode_scheduler::{DecodeScheduler, BUFFER_SIZE},
+		StreamingSound,
+	},
 };
 
 const ERROR_BUFFER_CAPACITY: usize = 1;
+const EVENT_BUFFER_CAPACITY: usize = 1;
 
 /// A streaming sound that is not playing yet.
 pub struct StreamingSoundData<Error: Send + 'static> {
@@ -75,6 +79,36 @@ impl<Error: Send> StreamingSoundData<Error> {
 		self
 	}
 
+	/**
+	Sets how much audio should be decoded before the sound starts playing.
+
+	# Examples
+
+	Making sure a sound starts exactly on a clock tick:
+
+	```no_run
+	use std::time::Duration;
+
+	use kira::{
+		manager::{AudioManager, AudioManagerSettings, backend::DefaultBackend},
+		sound::streaming::{StreamingSoundData, StreamingSoundSettings},
+		clock::ClockSpeed,
+	};
+
+	let mut manager = AudioManager::<DefaultBackend>::new(AudioManagerSettings::default())?;
+	let clock_handle = manager.add_clock(ClockSpeed::TicksPerMinute(120.0))?;
+	let sound = StreamingSoundData::from_file("sound.ogg")?
+		.start_time(clock_handle.time() + 4)
+		.start_when_buffered(Duration::from_millis(100));
+	# Result::<(), Box<dyn std::error::Error>>::Ok(())
+	```
+	*/
+	#[must_use = "This method consumes self and returns a modified StreamingSoundData, so the return value should be used"]
+	pub fn start_when_buffered(mut self, start_when_buffered: Duration) -> Self {
+		self.settings.start_when_buffered = start_when_buffered;
+		self
+	}
+
 	/// Sets where in the sound playback should start.
 	#[must_use = "This method consumes self and returns a modified StreamingSoundData, so the return value should be used"]
 	pub fn start_position(mut self, start_position: impl Into<PlaybackPosition>) -> Self {
@@ -386,10 +420,27 @@ impl<Error: Send + 'static> StreamingSoundData<Error> {
 			DecodeScheduler<Error>,
 		),
 		Error,
+	> {
+		self.split_with_buffer_size(BUFFER_SIZE)
+	}
+
+	/// Like [`split`](Self::split), but lets the caller choose how many frames
+	/// the decoder can buffer ahead of the playback position.
+	pub(crate) fn split_with_buffer_size(
+		self,
+		buffer_size: usize,
+	) -> Result<
+		(
+			StreamingSound,
+			StreamingSoundHandle<Error>,
+			DecodeScheduler<Error>,
+		),
+		Error,
 	> {
 		let (command_writers, command_readers, decode_scheduler_command_readers) =
 			command_writers_and_readers();
 		let (error_producer, error_consumer) = HeapRb::new(ERROR_BUFFER_CAPACITY).split();
+		let (event_producer, event_consumer) = HeapRb::new(EVENT_BUFFER_CAPACITY).split();
 		let sample_rate = self.decoder.sample_rate();
 		let shared = Arc::new(Shared::new());
 		let (scheduler, frame_consumer) = DecodeScheduler::new(
@@ -399,6 +450,7 @@ impl<Error: Send + 'static> StreamingSoundData<Error> {
 			shared.clone(),
 			decode_scheduler_command_readers,
 			error_producer,
+			buffer_size,
 		)?;
 		let sound = StreamingSound::new(
 			sample_rate,
@@ -406,12 +458,14 @@ impl<Error: Send + 'static> StreamingSoundData<Error> {
 			shared.clone(),
 			frame_consumer,
 			command_readers,
+			event_producer,
 			&scheduler,
 		);
 		let handle = StreamingSoundHandle {
 			shared,
 			command_writers,
 			error_consumer,
+			event_consumer,
 		};
 		Ok((sound, handle, scheduler))
 	}
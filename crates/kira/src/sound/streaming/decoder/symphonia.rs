@@ -1,3 +1,6 @@
+#[cfg(all(test, feature = "ogg"))]
+mod test;
+
 use std::convert::TryInto;
 
 use crate::{
@@ -6,7 +9,7 @@ use crate::{
 };
 use symphonia::core::{
 	codecs::Decoder,
-	formats::{FormatReader, SeekMode, SeekTo},
+	formats::{FormatReader, Packet, SeekMode, SeekTo},
 	io::{MediaSource, MediaSourceStream},
 	probe::Hint,
 };
@@ -17,6 +20,9 @@ pub(crate) struct SymphoniaDecoder {
 	sample_rate: u32,
 	num_frames: usize,
 	track_id: u32,
+	/// Frames that were decoded while seeking, which should be
+	/// returned by the next call to `decode`.
+	seeked_frames: Option<Vec<Frame>>,
 }
 
 impl SymphoniaDecoder {
@@ -53,8 +59,31 @@ impl SymphoniaDecoder {
 			sample_rate,
 			num_frames,
 			track_id,
+			seeked_frames: None,
 		})
 	}
+
+	fn next_packet(&mut self) -> Result<Packet, FromFileError> {
+		loop {
+			let packet = self.format_reader.next_packet()?;
+			if packet.track_id() == self.track_id {
+				return Ok(packet);
+			}
+		}
+	}
+
+	fn seek_to_timestamp(&mut self, ts: u64) -> Result<u64, FromFileError> {
+		Ok(self
+			.format_reader
+			.seek(
+				SeekMode::Accurate,
+				SeekTo::TimeStamp {
+					ts,
+					track_id: self.track_id,
+				},
+			)?
+			.actual_ts)
+	}
 }
 
 impl super::Decoder for SymphoniaDecoder {
@@ -69,22 +98,43 @@ impl super::Decoder for SymphoniaDecoder {
 	}
 
 	fn decode(&mut self) -> Result<Vec<Frame>, Self::Error> {
-		let packet = self.format_reader.next_packet()?;
+		if let Some(frames) = self.seeked_frames.take() {
+			return Ok(frames);
+		}
+		let packet = self.next_packet()?;
 		let buffer = self.decoder.decode(&packet)?;
 		load_frames_from_buffer_ref(&buffer)
 	}
 
 	fn seek(&mut self, index: usize) -> Result<usize, Self::Error> {
-		let seeked_to = self.format_reader.seek(
-			SeekMode::Accurate,
-			SeekTo::TimeStamp {
-				ts: index.try_into().expect("could not convert usize into u64"),
-				track_id: self.track_id,
-			},
-		)?;
-		Ok(seeked_to
-			.actual_ts
-			.try_into()
-			.expect("could not convert u64 into usize"))
+		self.seeked_frames = None;
+		/*
+			after being reset, some decoders (like the vorbis decoder) need
+			to decode a packet before they can output audio again. with
+			formats like ogg, seeking to the start of the packet that contains
+			the requested frame lands on the packet before it, which gives
+			the decoder a packet to prepare with. that way, the first frame
+			the decoder outputs is still at or before the requested frame.
+		*/
+		let packet_ts =
+			self.seek_to_timestamp(index.try_into().expect("could not convert usize into u64"))?;
+		self.seek_to_timestamp(packet_ts)?;
+		// the decoder's internal state is only valid for the
+		// packets it was decoding before the seek
+		self.decoder.reset();
+		// the first frame the decoder outputs is the frame we've actually
+		// seeked to, so hold on to the decoded frames for the next call
+		// to `decode`
+		loop {
+			let packet = self.next_packet()?;
+			let buffer = self.decoder.decode(&packet)?;
+			if buffer.frames() > 0 {
+				self.seeked_frames = Some(load_frames_from_buffer_ref(&buffer)?);
+				return Ok(packet
+					.ts()
+					.try_into()
+					.expect("could not convert u64 into usize"));
+			}
+		}
 	}
 }
@@ -0,0 +1,78 @@
+use std::io::Cursor;
+
+use crate::{frame::Frame, sound::streaming::Decoder};
+
+use super::SymphoniaDecoder;
+
+/// Tests that seeking in an encoded file outputs the same audio
+/// as decoding the file from the beginning.
+#[test]
+fn seek_matches_decoding_from_start() {
+	let expected_frames = decode_all(&mut test_decoder());
+	let num_frames = expected_frames.len();
+
+	let mut decoder = test_decoder();
+	for requested_index in [
+		num_frames / 2,
+		0,
+		1,
+		1024,
+		2047,
+		num_frames / 3,
+		num_frames - 1,
+	] {
+		// decode a bit after each seek so the decoder has state
+		// from before the next seek
+		let index = decoder.seek(requested_index).unwrap();
+		assert!(index <= requested_index);
+		let mut frames = vec![];
+		while index + frames.len() <= requested_index {
+			frames.extend(decoder.decode().unwrap());
+		}
+		for (i, (frame, expected_frame)) in frames.iter().zip(&expected_frames[index..]).enumerate()
+		{
+			assert!(
+				frames_match(*frame, *expected_frame),
+				"frame {} after seeking to {} was {:?}, expected {:?}",
+				index + i,
+				requested_index,
+				frame,
+				expected_frame
+			);
+		}
+	}
+}
+
+/// Tests that only the packets from the default track of a file
+/// are decoded.
+#[test]
+fn ignores_other_tracks() {
+	// an ogg file with two copies of the audio in `test.ogg`
+	// as separate tracks
+	let mut decoder = SymphoniaDecoder::new(Box::new(Cursor::new(
+		include_bytes!("two_tracks.ogg").as_slice(),
+	)))
+	.unwrap();
+	let frames = decode_all(&mut decoder);
+	let expected_frames = decode_all(&mut test_decoder());
+	assert_eq!(frames.len(), expected_frames.len());
+	for (frame, expected_frame) in frames.iter().zip(&expected_frames) {
+		assert!(frames_match(*frame, *expected_frame));
+	}
+}
+
+fn test_decoder() -> SymphoniaDecoder {
+	SymphoniaDecoder::new(Box::new(Cursor::new(include_bytes!("test.ogg").as_slice()))).unwrap()
+}
+
+fn decode_all(decoder: &mut SymphoniaDecoder) -> Vec<Frame> {
+	let mut frames = vec![];
+	while frames.len() < decoder.num_frames() {
+		frames.extend(decoder.decode().unwrap());
+	}
+	frames
+}
+
+fn frames_match(a: Frame, b: Frame) -> bool {
+	(a.left - b.left).abs() < 1.0e-6 && (a.right - b.right).abs() < 1.0e-6
+}
@@ -0,0 +1,15 @@
+use std::time::Duration;
+
+/// Something that happened to a streaming sound on the audio thread.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StreamingSoundEvent {
+	/**
+	The sound reached its [`StartTime::ClockTime`](crate::StartTime::ClockTime),
+	but not enough audio was buffered to start playing, so the sound
+	started late by the given amount of time.
+
+	The amount of audio that needs to be buffered can be set with
+	[`StreamingSoundSettings::start_when_buffered`](super::StreamingSoundSettings::start_when_buffered).
+	*/
+	StartDelayed(Duration),
+}
@@ -1,6 +1,7 @@
 use std::{
 	fmt::{Debug, Formatter},
 	sync::Arc,
+	time::Duration,
 };
 
 use crate::{
@@ -11,13 +12,14 @@ use crate::{
 };
 use ringbuf::HeapConsumer;
 
-use super::{sound::Shared, CommandWriters};
+use super::{sound::Shared, CommandWriters, StreamingSoundEvent};
 
 /// Controls a streaming sound.
 pub struct StreamingSoundHandle<Error> {
 	pub(super) shared: Arc<Shared>,
 	pub(super) command_writers: CommandWriters,
 	pub(super) error_consumer: HeapConsumer<Error>,
+	pub(super) event_consumer: HeapConsumer<StreamingSoundEvent>,
 }
 
 impl<Error> StreamingSoundHandle<Error> {
@@ -33,6 +35,13 @@ impl<Error> StreamingSoundHandle<Error> {
 		self.shared.position()
 	}
 
+	/// Returns the amount of audio that has been decoded ahead of
+	/// the current playback position.
+	#[must_use]
+	pub fn buffered(&self) -> Duration {
+		Duration::from_secs_f64(self.shared.buffered())
+	}
+
 	handle_param_setters! {
 		/**
 		Sets the volume of the sound.
@@ -310,6 +319,12 @@ impl<Error> StreamingSoundHandle<Error> {
 	pub fn pop_error(&mut self) -> Option<Error> {
 		self.error_consumer.pop()
 	}
+
+	/// Returns an event that occurred while the sound was playing, if any.
+	#[must_use]
+	pub fn pop_event(&mut self) -> Option<StreamingSoundEvent> {
+		self.event_consumer.pop()
+	}
 }
 
 impl<Error: Debug> Debug for StreamingSoundHandle<Error> {
@@ -318,6 +333,7 @@ impl<Error: Debug> Debug for StreamingSoundHandle<Error> {
 			.field("shared", &self.shared)
 			.field("command_writers", &self.command_writers)
 			.field("error_consumer", &HeapConsumerDebug)
+			.field("event_consumer", &HeapConsumerDebug)
 			.finish()
 	}
 }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crate::{
 	sound::{IntoOptionalRegion, PlaybackPosition, PlaybackRate, Region},
 	tween::{Tween, Value},
@@ -9,6 +11,16 @@ use crate::{
 pub struct StreamingSoundSettings {
 	/// When the sound should start playing.
 	pub start_time: StartTime,
+	/**
+	How much audio should be decoded before the sound starts playing.
+
+	The sound will not be considered to have reached its start time
+	until at least this much audio is buffered (or the end of the
+	audio has been decoded). Decoding begins as soon as the sound is
+	played, so when combined with a [`StartTime::ClockTime`], the audio
+	is prefetched while the sound waits for the clock.
+	*/
+	pub start_when_buffered: Duration,
 	/// Where in the sound playback should start.
 	pub start_position: PlaybackPosition,
 	/// The portion of the sound that should be looped.
@@ -35,6 +47,7 @@ impl StreamingSoundSettings {
 	pub fn new() -> Self {
 		Self {
 			start_time: StartTime::Immediate,
+			start_when_buffered: Duration::ZERO,
 			start_position: PlaybackPosition::Seconds(0.0),
 			loop_region: None,
 			volume: Value::Fixed(Volume::Amplitude(1.0)),
@@ -54,6 +67,15 @@ impl StreamingSoundSettings {
 		}
 	}
 
+	/// Sets how much audio should be decoded before the sound starts playing.
+	#[must_use = "This method consumes self and returns a modified StreamingSoundSettings, so the return value should be used"]
+	pub fn start_when_buffered(self, start_when_buffered: Duration) -> Self {
+		Self {
+			start_when_buffered,
+			..self
+		}
+	}
+
 	/// Sets where in the sound playback should start.
 	#[must_use = "This method consumes self and returns a modified StreamingSoundSettings, so the return value should be used"]
 	pub fn start_position(self, start_position: impl Into<PlaybackPosition>) -> Self {
@@ -124,6 +146,11 @@ impl StreamingSoundSettings {
 			..self
 		}
 	}
+
+	#[must_use]
+	pub(crate) fn start_when_buffered_frames(&self, sample_rate: u32) -> usize {
+		(self.start_when_buffered.as_secs_f64() * sample_rate as f64).ceil() as usize
+	}
 }
 
 impl Default for StreamingSoundSettings {
@@ -3,9 +3,12 @@ pub(crate) mod decode_scheduler;
 #[cfg(test)]
 mod test;
 
-use std::sync::{
-	atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
-	Arc,
+use std::{
+	sync::{
+		atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
+		Arc,
+	},
+	time::Duration,
 };
 
 use crate::{
@@ -17,9 +20,9 @@ use crate::{
 	tween::{Parameter, Tween, Value},
 	OutputDestination, StartTime, Volume,
 };
-use ringbuf::HeapConsumer;
+use ringbuf::{HeapConsumer, HeapProducer};
 
-use super::{CommandReaders, StreamingSoundSettings};
+use super::{CommandReaders, StreamingSoundEvent, StreamingSoundSettings};
 
 use self::decode_scheduler::DecodeScheduler;
 
@@ -27,6 +30,7 @@ use self::decode_scheduler::DecodeScheduler;
 pub(crate) struct Shared {
 	state: AtomicU8,
 	position: AtomicU64,
+	buffered: AtomicU64,
 	reached_end: AtomicBool,
 	encountered_error: AtomicBool,
 }
@@ -36,6 +40,7 @@ impl Shared {
 	pub fn new() -> Self {
 		Self {
 			position: AtomicU64::new(0.0f64.to_bits()),
+			buffered: AtomicU64::new(0.0f64.to_bits()),
 			state: AtomicU8::new(PlaybackState::Playing as u8),
 			reached_end: AtomicBool::new(false),
 			encountered_error: AtomicBool::new(false),
@@ -59,6 +64,11 @@ impl Shared {
 		f64::from_bits(self.position.load(Ordering::SeqCst))
 	}
 
+	#[must_use]
+	pub fn buffered(&self) -> f64 {
+		f64::from_bits(self.buffered.load(Ordering::SeqCst))
+	}
+
 	#[must_use]
 	pub fn reached_end(&self) -> bool {
 		self.reached_end.load(Ordering::SeqCst)
@@ -76,6 +86,10 @@ pub(crate) struct StreamingSound {
 	frame_consumer: HeapConsumer<TimestampedFrame>,
 	output_destination: OutputDestination,
 	start_time: StartTime,
+	start_when_buffered: usize,
+	waiting_for_buffer: bool,
+	report_start_delay: bool,
+	start_delay: f64,
 	state: PlaybackState,
 	volume_fade: Parameter<Volume>,
 	volume_fade_start_time: StartTime,
@@ -86,6 +100,7 @@ pub(crate) struct StreamingSound {
 	playback_rate: Parameter<PlaybackRate>,
 	panning: Parameter,
 	shared: Arc<Shared>,
+	event_producer: HeapProducer<StreamingSoundEvent>,
 }
 
 impl StreamingSound {
@@ -96,6 +111,7 @@ impl StreamingSound {
 		shared: Arc<Shared>,
 		frame_consumer: HeapConsumer<TimestampedFrame>,
 		command_readers: CommandReaders,
+		event_producer: HeapProducer<StreamingSoundEvent>,
 		scheduler: &DecodeScheduler<Error>,
 	) -> Self {
 		let current_frame = scheduler.current_frame();
@@ -103,12 +119,18 @@ impl StreamingSound {
 		shared
 			.position
 			.store(start_position.to_bits(), Ordering::SeqCst);
+		// we need at least one buffered frame to start playing
+		let start_when_buffered = settings.start_when_buffered_frames(sample_rate).max(1);
 		Self {
 			command_readers,
 			sample_rate,
 			frame_consumer,
 			output_destination: settings.output_destination,
 			start_time: settings.start_time,
+			start_when_buffered,
+			waiting_for_buffer: true,
+			report_start_delay: matches!(settings.start_time, StartTime::ClockTime(_)),
+			start_delay: 0.0,
 			state: PlaybackState::Playing,
 			volume_fade: create_volume_fade_parameter(settings.fade_in_tween),
 			volume_fade_start_time: StartTime::Immediate,
@@ -119,6 +141,7 @@ impl StreamingSound {
 			playback_rate: Parameter::new(settings.playback_rate, PlaybackRate::Factor(1.0)),
 			panning: Parameter::new(settings.panning, 0.5),
 			shared,
+			event_producer,
 		}
 	}
 
@@ -127,6 +150,11 @@ impl StreamingSound {
 		self.shared.state.store(state as u8, Ordering::SeqCst);
 	}
 
+	/// Stops the sound without fading out.
+	pub(crate) fn stop_immediately(&mut self) {
+		self.set_state(PlaybackState::Stopped);
+	}
+
 	fn update_current_frame(&mut self) {
 		let current_frame = &mut self.current_frame;
 		let (a, b) = self.frame_consumer.as_slices();
@@ -151,6 +179,34 @@ impl StreamingSound {
 		frames
 	}
 
+	/// Returns the number of frames that have been decoded ahead of
+	/// the previous frame.
+	#[must_use]
+	fn num_buffered_frames(&self) -> usize {
+		self.frame_consumer.len().saturating_sub(1)
+	}
+
+	/// Returns `true` if the sound should keep waiting for the decoder
+	/// before starting playback.
+	fn wait_for_buffer(&mut self, dt: f64) -> bool {
+		if !self.waiting_for_buffer {
+			return false;
+		}
+		if self.num_buffered_frames() < self.start_when_buffered && !self.shared.reached_end() {
+			self.start_delay += dt;
+			return true;
+		}
+		self.waiting_for_buffer = false;
+		if self.report_start_delay && self.start_delay > 0.0 {
+			self.event_producer
+				.push(StreamingSoundEvent::StartDelayed(Duration::from_secs_f64(
+					self.start_delay,
+				)))
+				.ok();
+		}
+		false
+	}
+
 	#[must_use]
 	fn position(&self) -> f64 {
 		(self.current_frame as f64 + self.fractional_position) / self.sample_rate as f64
@@ -203,6 +259,10 @@ impl Sound for StreamingSound {
 		self.shared
 			.position
 			.store(self.position().to_bits(), Ordering::SeqCst);
+		self.shared.buffered.store(
+			(self.num_buffered_frames() as f64 / self.sample_rate as f64).to_bits(),
+			Ordering::SeqCst,
+		);
 		self.read_commands();
 	}
 
@@ -253,6 +313,10 @@ impl Sound for StreamingSound {
 		if matches!(self.state, PlaybackState::Paused | PlaybackState::Stopped) {
 			return Frame::ZERO;
 		}
+		// don't start playing until enough audio is buffered
+		if self.wait_for_buffer(dt) {
+			return Frame::ZERO;
+		}
 		// pause playback while waiting for audio data. the first frame
 		// in the ringbuffer is the previous frame, so we need to make
 		// sure there's at least 2 before we continue playing.
@@ -15,8 +15,11 @@ use ringbuf::{HeapConsumer, HeapProducer, HeapRb};
 
 use super::{Shared, TimestampedFrame};
 
-const BUFFER_SIZE: usize = 16_384;
-const DECODER_THREAD_SLEEP_DURATION: Duration = Duration::from_millis(1);
+pub(crate) const BUFFER_SIZE: usize = 16_384;
+pub(crate) const DECODER_THREAD_SLEEP_DURATION: Duration = Duration::from_millis(1);
+// the previous frame plus the three frames after it, which are
+// needed for interpolation
+const MIN_BUFFER_SIZE: usize = 4;
 
 pub(crate) enum NextStep {
 	Continue,
@@ -46,8 +49,19 @@ impl<Error: Send + 'static> DecodeScheduler<Error> {
 		shared: Arc<Shared>,
 		command_readers: DecodeSchedulerCommandReaders,
 		error_producer: HeapProducer<Error>,
+		buffer_size: usize,
 	) -> Result<(Self, HeapConsumer<TimestampedFrame>), Error> {
-		let (mut frame_producer, frame_consumer) = HeapRb::new(BUFFER_SIZE).split();
+		let sample_rate = decoder.sample_rate();
+		// make sure the ringbuffer can hold as much audio as the sound
+		// waits for before starting, plus the pre-seeded frame below
+		let buffer_size = buffer_size
+			.max(
+				settings
+					.start_when_buffered_frames(sample_rate)
+					.saturating_add(1),
+			)
+			.max(MIN_BUFFER_SIZE);
+		let (mut frame_producer, frame_consumer) = HeapRb::new(buffer_size).split();
 		// pre-seed the frame ringbuffer with a zero frame. this is the "previous" frame
 		// when the sound just started.
 		frame_producer
@@ -56,7 +70,6 @@ impl<Error: Send + 'static> DecodeScheduler<Error> {
 				index: 0,
 			})
 			.expect("The frame producer shouldn't be full because we just created it");
-		let sample_rate = decoder.sample_rate();
 		let num_frames = if let Some((start, end)) = slice {
 			end - start
 		} else {
@@ -99,14 +112,16 @@ impl<Error: Send + 'static> DecodeScheduler<Error> {
 					NextStep::Wait => std::thread::sleep(DECODER_THREAD_SLEEP_DURATION),
 					NextStep::End => break,
 				},
-				Err(error) => {
-					self.error_producer.push(error).ok();
-					self.shared.encountered_error.store(true, Ordering::SeqCst);
-				}
+				Err(error) => self.report_error(error),
 			}
 		});
 	}
 
+	pub fn report_error(&mut self, error: Error) {
+		self.error_producer.push(error).ok();
+		self.shared.encountered_error.store(true, Ordering::SeqCst);
+	}
+
 	pub fn run(&mut self) -> Result<NextStep, Error> {
 		// if the sound was manually stopped, end the thread
 		if self.shared.state() == PlaybackState::Stopped {
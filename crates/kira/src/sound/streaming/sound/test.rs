@@ -5,7 +5,10 @@ use crate::{
 	frame::Frame,
 	modulator::value_provider::MockModulatorValueProviderBuilder,
 	sound::{
-		streaming::{decoder::mock::MockDecoder, StreamingSoundData, StreamingSoundSettings},
+		streaming::{
+			decoder::mock::MockDecoder, StreamingSoundData, StreamingSoundEvent,
+			StreamingSoundSettings,
+		},
 		PlaybackState, Sound,
 	},
 	tween::Tween,
@@ -451,6 +454,232 @@ fn waits_for_start_time() {
 	}
 }
 
+/// Tests that a `StreamingSound` with a clock start time will start
+/// exactly on time if the decoder had enough time to buffer audio
+/// before the clock reached the start time.
+#[test]
+#[allow(clippy::float_cmp)]
+fn starts_on_time_when_buffered() {
+	let (clock_info_provider, clock_id) = {
+		let mut builder = MockClockInfoProviderBuilder::new(1);
+		let clock_id = builder.add(true, 0, 0.0).unwrap();
+		(builder.build(), clock_id)
+	};
+
+	let data = StreamingSoundData {
+		decoder: Box::new(MockDecoder::new(
+			(1..100).map(|i| Frame::from_mono(i as f32)).collect(),
+		)),
+		settings: StreamingSoundSettings::new()
+			.start_time(ClockTime {
+				clock: clock_id,
+				ticks: 1,
+				fraction: 0.0,
+			})
+			.start_when_buffered(Duration::from_secs(5)),
+		slice: None,
+	};
+	let (mut sound, mut handle, mut scheduler) = data.split().unwrap();
+
+	// simulate a slow decoder that only decodes one frame
+	// for every frame of audio the sound outputs
+	for i in 1..=5 {
+		scheduler.run().unwrap();
+		assert_eq!(
+			sound.process(
+				1.0,
+				&clock_info_provider,
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::from_mono(0.0)
+		);
+		sound.on_start_processing();
+		assert_eq!(handle.buffered(), Duration::from_secs(i));
+	}
+
+	let clock_info_provider = {
+		let mut builder = MockClockInfoProviderBuilder::new(1);
+		builder.add(true, 1, 0.0).unwrap();
+		builder.build()
+	};
+
+	// enough audio was buffered before the clock reached the
+	// start time, so the sound should start playing immediately
+	assert_eq!(
+		sound.process(
+			1.0,
+			&clock_info_provider,
+			&MockModulatorValueProviderBuilder::new(0).build()
+		),
+		Frame::from_mono(1.0).panned(0.5)
+	);
+	assert_eq!(handle.pop_event(), None);
+}
+
+/// Tests that a `StreamingSound` with a clock start time will wait
+/// for enough audio to be buffered if the decoder didn't have enough
+/// time to buffer audio, and that it reports how late it started.
+#[test]
+fn delays_start_when_not_buffered() {
+	let (clock_info_provider, clock_id) = {
+		let mut builder = MockClockInfoProviderBuilder::new(1);
+		let clock_id = builder.add(true, 1, 0.0).unwrap();
+		(builder.build(), clock_id)
+	};
+
+	let data = StreamingSoundData {
+		decoder: Box::new(MockDecoder::new(
+			(1..100).map(|i| Frame::from_mono(i as f32)).collect(),
+		)),
+		settings: StreamingSoundSettings::new()
+			.start_time(ClockTime {
+				clock: clock_id,
+				ticks: 1,
+				fraction: 0.0,
+			})
+			.start_when_buffered(Duration::from_secs(4)),
+		slice: None,
+	};
+	let (mut sound, mut handle, mut scheduler) = data.split().unwrap();
+
+	// simulate a slow decoder that only decodes one frame for every
+	// two frames of audio the sound outputs. the clock has already
+	// reached the start time, but the sound should wait for the decoder.
+	for _ in 0..3 {
+		scheduler.run().unwrap();
+		for _ in 0..2 {
+			assert_eq!(
+				sound.process(
+					1.0,
+					&clock_info_provider,
+					&MockModulatorValueProviderBuilder::new(0).build()
+				),
+				Frame::ZERO
+			);
+		}
+	}
+	assert_eq!(handle.pop_event(), None);
+
+	scheduler.run().unwrap();
+	assert_eq!(
+		sound.process(
+			1.0,
+			&clock_info_provider,
+			&MockModulatorValueProviderBuilder::new(0).build()
+		),
+		Frame::from_mono(1.0).panned(0.5)
+	);
+	assert_eq!(
+		handle.pop_event(),
+		Some(StreamingSoundEvent::StartDelayed(Duration::from_secs(6)))
+	);
+}
+
+/// Tests that a `StreamingSound` with an immediate start time will wait
+/// for enough audio to be buffered without reporting a delayed start.
+#[test]
+fn waits_for_buffer_with_immediate_start_time() {
+	let data = StreamingSoundData {
+		decoder: Box::new(MockDecoder::new(
+			(1..100).map(|i| Frame::from_mono(i as f32)).collect(),
+		)),
+		settings: StreamingSoundSettings::new().start_when_buffered(Duration::from_secs(3)),
+		slice: None,
+	};
+	let (mut sound, mut handle, mut scheduler) = data.split().unwrap();
+
+	for _ in 0..2 {
+		scheduler.run().unwrap();
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::ZERO
+		);
+	}
+
+	scheduler.run().unwrap();
+	assert_eq!(
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build()
+		),
+		Frame::from_mono(1.0).panned(0.5)
+	);
+	assert_eq!(handle.pop_event(), None);
+}
+
+/// Tests that a `StreamingSound` will start playing if the end of the
+/// audio is decoded before the requested amount of audio is buffered.
+#[test]
+fn starts_when_end_is_reached_before_buffered() {
+	let data = StreamingSoundData {
+		decoder: Box::new(MockDecoder::new(
+			(1..4).map(|i| Frame::from_mono(i as f32)).collect(),
+		)),
+		settings: StreamingSoundSettings::new().start_when_buffered(Duration::from_secs(10)),
+		slice: None,
+	};
+	let (mut sound, _, mut scheduler) = data.split().unwrap();
+
+	for _ in 0..2 {
+		assert!(matches!(scheduler.run().unwrap(), NextStep::Continue));
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::ZERO
+		);
+	}
+
+	assert!(matches!(scheduler.run().unwrap(), NextStep::End));
+	for i in 1..4 {
+		assert_eq!(
+			sound.process(
+				1.0,
+				&MockClockInfoProviderBuilder::new(0).build(),
+				&MockModulatorValueProviderBuilder::new(0).build()
+			),
+			Frame::from_mono(i as f32).panned(0.5)
+		);
+	}
+}
+
+/// Tests that a `StreamingSound` can buffer more audio than the
+/// default buffer size before starting if requested.
+#[test]
+fn buffers_more_than_default_buffer_size() {
+	const NUM_FRAMES: usize = 20_000;
+
+	let data = StreamingSoundData {
+		decoder: Box::new(MockDecoder::new(vec![
+			Frame::from_mono(1.0);
+			NUM_FRAMES + 1
+		])),
+		settings: StreamingSoundSettings::new()
+			.start_when_buffered(Duration::from_secs(NUM_FRAMES as u64)),
+		slice: None,
+	};
+	let (mut sound, _, mut scheduler) = data.split().unwrap();
+
+	for _ in 0..NUM_FRAMES {
+		assert!(matches!(scheduler.run().unwrap(), NextStep::Continue));
+	}
+	assert_eq!(
+		sound.process(
+			1.0,
+			&MockClockInfoProviderBuilder::new(0).build(),
+			&MockModulatorValueProviderBuilder::new(0).build()
+		),
+		Frame::from_mono(1.0).panned(0.5)
+	);
+}
+
 /// Tests that a `StreamingSound` will stop (allowing it to be removed)
 /// if it's waiting on a clock that no longer exists before it can
 /// start.
@@ -0,0 +1,131 @@
+use std::sync::{
+	atomic::{AtomicBool, AtomicU32, AtomicUsize, Ordering},
+	Arc,
+};
+
+use kira::{
+	clock::clock_info::ClockInfoProvider,
+	manager::{
+		backend::mock::{MockBackend, MockBackendSettings},
+		AudioManager, AudioManagerSettings,
+	},
+	modulator::value_provider::ModulatorValueProvider,
+	sound::{
+		static_sound::{StaticSoundData, StaticSoundSettings},
+		PlaybackState, SoundProcessor,
+	},
+	Frame,
+};
+
+#[derive(Default)]
+struct TestProcessorState {
+	num_inits: AtomicUsize,
+	sample_rate: AtomicU32,
+	dropped: AtomicBool,
+}
+
+struct TestProcessor(Arc<TestProcessorState>);
+
+impl SoundProcessor for TestProcessor {
+	fn init(&mut self, sample_rate: u32) {
+		self.0.num_inits.fetch_add(1, Ordering::SeqCst);
+		self.0.sample_rate.store(sample_rate, Ordering::SeqCst);
+	}
+
+	fn on_change_sample_rate(&mut self, sample_rate: u32) {
+		self.0.sample_rate.store(sample_rate, Ordering::SeqCst);
+	}
+
+	fn process(
+		&mut self,
+		input: Frame,
+		_dt: f64,
+		_clock_info_provider: &ClockInfoProvider,
+		_modulator_value_provider: &ModulatorValueProvider,
+	) -> Frame {
+		input
+	}
+}
+
+impl Drop for TestProcessor {
+	fn drop(&mut self) {
+		self.0.dropped.store(true, Ordering::SeqCst);
+	}
+}
+
+/// Tests that a sound processor is initialized once when the sound
+/// is played and dropped on the gameplay thread after the sound finishes.
+#[test]
+fn sound_processor_lifecycle() {
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+		backend_settings: MockBackendSettings { sample_rate: 1 },
+		..Default::default()
+	})
+	.unwrap();
+	let state = Arc::new(TestProcessorState::default());
+	let make_sound = |state: &Arc<TestProcessorState>| {
+		let state = state.clone();
+		StaticSoundData {
+			sample_rate: 1,
+			frames: Arc::new([Frame::from_mono(1.0); 2]),
+			settings: StaticSoundSettings::new()
+				.with_processor(move || TestProcessor(state.clone())),
+			slice: None,
+		}
+	};
+	let handle = manager.play(make_sound(&state)).unwrap();
+	assert_eq!(state.num_inits.load(Ordering::SeqCst), 1);
+	assert_eq!(state.sample_rate.load(Ordering::SeqCst), 1);
+
+	// play the sound until it finishes
+	let backend = manager.backend_mut();
+	for _ in 0..10 {
+		backend.on_start_processing();
+		let _ = backend.process();
+	}
+	assert_eq!(handle.state(), PlaybackState::Stopped);
+	assert_eq!(state.num_inits.load(Ordering::SeqCst), 1);
+	assert!(!state.dropped.load(Ordering::SeqCst));
+
+	// the finished sound should be removed from the renderer, but the
+	// processor should not be dropped on the audio thread
+	backend.on_start_processing();
+	assert!(!state.dropped.load(Ordering::SeqCst));
+
+	// the processor is dropped when the gameplay thread collects
+	// unused resources
+	let other_state = Arc::new(TestProcessorState::default());
+	manager.play(make_sound(&other_state)).unwrap();
+	assert!(state.dropped.load(Ordering::SeqCst));
+	assert_eq!(state.num_inits.load(Ordering::SeqCst), 1);
+	assert!(!other_state.dropped.load(Ordering::SeqCst));
+}
+
+/// Tests that a sound processor is notified when the sample rate
+/// of the renderer changes.
+#[test]
+fn sound_processor_receives_sample_rate_changes() {
+	let mut manager = AudioManager::<MockBackend>::new(AudioManagerSettings {
+		backend_settings: MockBackendSettings { sample_rate: 1 },
+		..Default::default()
+	})
+	.unwrap();
+	let state = Arc::new(TestProcessorState::default());
+	let processor_state = state.clone();
+	manager
+		.play(StaticSoundData {
+			sample_rate: 1,
+			frames: Arc::new([Frame::from_mono(1.0); 10]),
+			settings: StaticSoundSettings::new()
+				.with_processor(move || TestProcessor(processor_state.clone())),
+			slice: None,
+		})
+		.unwrap();
+	assert_eq!(state.sample_rate.load(Ordering::SeqCst), 1);
+
+	let backend = manager.backend_mut();
+	backend.on_start_processing();
+	backend.set_sample_rate(2);
+	assert_eq!(state.sample_rate.load(Ordering::SeqCst), 2);
+	assert_eq!(state.num_inits.load(Ordering::SeqCst), 1);
+}